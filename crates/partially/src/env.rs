@@ -0,0 +1,60 @@
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+use core::{fmt, str::FromStr};
+
+/// The error returned when an environment variable can't be parsed.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct EnvError {
+    /// The name of the variable.
+    pub variable: String,
+    /// The message of the parse error.
+    pub message: String,
+}
+
+impl fmt::Display for EnvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "failed to parse environment variable `{}`: {}",
+            self.variable, self.message
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for EnvError {}
+
+// note: the following are used by the `env` derive option, through `__private`
+
+/// Gets the name of the variable for the field named `field` (in upper case), as `{prefix}_{field}`.
+///
+/// Note: When `prefix` is empty, `field` is used as is.
+pub fn env_name(prefix: &str, field: &str) -> String {
+    if prefix.is_empty() {
+        field.into()
+    } else {
+        format!("{}_{}", prefix, field)
+    }
+}
+
+/// Gets the prefix for the fields of the nested field named `field` (in upper case), such that their variables
+/// are separated by `__`.
+pub fn env_nested_prefix(prefix: &str, field: &str) -> String {
+    let mut prefix = env_name(prefix, field);
+    prefix.push('_');
+    prefix
+}
+
+/// Parses `value`, the value of the variable named `variable`.
+pub fn parse_env<T>(variable: &str, value: &str) -> Result<T, EnvError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    value.parse().map_err(|err: T::Err| EnvError {
+        variable: variable.into(),
+        message: err.to_string(),
+    })
+}
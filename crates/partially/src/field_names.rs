@@ -0,0 +1,42 @@
+use core::iter::FusedIterator;
+
+use crate::FieldNames;
+
+/// An iterator over the names of the fields of a partial that are currently present.
+///
+/// Note: Created by [`FieldNames::some_field_names`].
+#[derive(Debug, Clone)]
+pub struct SomeFieldNames<'a, T: ?Sized> {
+    partial: &'a T,
+    index: usize,
+}
+
+impl<'a, T: FieldNames + ?Sized> SomeFieldNames<'a, T> {
+    /// Creates a new [`SomeFieldNames`] over the fields of `partial`.
+    pub fn new(partial: &'a T) -> Self {
+        Self { partial, index: 0 }
+    }
+}
+
+impl<'a, T: FieldNames + ?Sized> Iterator for SomeFieldNames<'a, T> {
+    type Item = &'static str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(name) = T::FIELD_NAMES.get(self.index) {
+            let index = self.index;
+            self.index += 1;
+
+            if self.partial.field_is_some(index) {
+                return Some(name);
+            }
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(T::FIELD_NAMES.len().saturating_sub(self.index)))
+    }
+}
+
+impl<'a, T: FieldNames + ?Sized> FusedIterator for SomeFieldNames<'a, T> {}
@@ -0,0 +1,87 @@
+use crate::Partial;
+
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, rc::Rc};
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+use alloc::sync::Arc;
+
+/// Applies to the contained value, if any.
+///
+/// Note: When [`None`], nothing is applied and `false` is returned. See [`PartialOption`] for
+/// applying to an inserted value instead.
+impl<T: Partial> Partial for Option<T> {
+    type Item = T::Item;
+
+    fn apply_some(&mut self, partial: Self::Item) -> bool {
+        match self {
+            Some(value) => value.apply_some(partial),
+            None => false,
+        }
+    }
+}
+
+impl<T: Partial + ?Sized> Partial for &mut T {
+    type Item = T::Item;
+
+    fn apply_some(&mut self, partial: Self::Item) -> bool {
+        (**self).apply_some(partial)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Partial + ?Sized> Partial for Box<T> {
+    type Item = T::Item;
+
+    fn apply_some(&mut self, partial: Self::Item) -> bool {
+        (**self).apply_some(partial)
+    }
+}
+
+/// Applies using copy-on-write, cloning the contained value if it is shared.
+#[cfg(feature = "alloc")]
+impl<T: Partial + Clone> Partial for Rc<T> {
+    type Item = T::Item;
+
+    fn apply_some(&mut self, partial: Self::Item) -> bool {
+        Rc::make_mut(self).apply_some(partial)
+    }
+}
+
+/// Applies using copy-on-write, cloning the contained value if it is shared.
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+impl<T: Partial + Clone> Partial for Arc<T> {
+    type Item = T::Item;
+
+    fn apply_some(&mut self, partial: Self::Item) -> bool {
+        Arc::make_mut(self).apply_some(partial)
+    }
+}
+
+/// Allows applying a [`Partial::Item`] to an [`Option<T>`], inserting a value when it is [`None`].
+pub trait PartialOption<T: Partial> {
+    /// Applies [`Some`] values from [`Partial::Item`] to the contained value, first inserting the
+    /// result of `f` if there is no contained value.
+    ///
+    /// Note: Returns `true` when a value was inserted, or when updates were made.
+    fn apply_some_or_insert_with<F: FnOnce() -> T>(&mut self, partial: T::Item, f: F) -> bool;
+
+    /// Applies [`Some`] values from [`Partial::Item`] to the contained value, first inserting
+    /// [`Default::default()`] if there is no contained value.
+    ///
+    /// Note: Returns `true` when a value was inserted, or when updates were made.
+    fn apply_some_or_default(&mut self, partial: T::Item) -> bool
+    where
+        T: Default,
+    {
+        self.apply_some_or_insert_with(partial, T::default)
+    }
+}
+
+impl<T: Partial> PartialOption<T> for Option<T> {
+    fn apply_some_or_insert_with<F: FnOnce() -> T>(&mut self, partial: T::Item, f: F) -> bool {
+        let will_insert = self.is_none();
+
+        self.get_or_insert_with(f).apply_some(partial) || will_insert
+    }
+}
@@ -0,0 +1,622 @@
+//! Support for converting partials to and from JSON patch documents.
+//!
+//! Note: Requires the `serde_json` feature.
+
+use alloc::{string::String, vec::Vec};
+
+use core::fmt;
+
+use serde::{
+    de::{self, Deserialize, DeserializeOwned},
+    ser::{self, Serialize},
+};
+use serde_json::{Map, Value};
+
+use crate::{Diff, Partial};
+
+/// Builds a partial from a JSON Merge Patch ([RFC 7386](https://www.rfc-editor.org/rfc/rfc7386)) document.
+///
+/// Members that are absent from `patch` are [`None`] (or `Patch::Missing`), and members that are `null` are
+/// `Patch::Null` for fields using the `patch` field option. Nested objects are deserialized into `nested` fields.
+///
+/// The partial must represent `patch` exactly, such that [`to_merge_patch`] emits it again, otherwise an error is
+/// returned. This rejects members the partial doesn't have, and `null` members of fields that don't use the `patch`
+/// field option (as they can't be removed). Objects of fields that don't use the `nested` field option replace the
+/// value as a whole, so they must contain each member of the value, as it's serialized.
+///
+/// Note: The partial must deserialize absent members as [`None`], for example by using `#[serde(default)]`.
+pub fn from_merge_patch<T>(patch: Value) -> Result<T, serde_json::Error>
+where
+    T: Serialize + DeserializeOwned,
+{
+    let partial = T::deserialize(&patch)?;
+
+    if let Some(pointer) = mismatched_member(&patch, &to_merge_patch(&partial)?, String::new()) {
+        return Err(de::Error::custom(format_args!(
+            "member `{}` can't be represented by the partial",
+            pointer
+        )));
+    }
+
+    Ok(partial)
+}
+
+/// Gets the JSON Pointer of the first member that differs between `expected` and `actual`, if any.
+fn mismatched_member(expected: &Value, actual: &Value, pointer: String) -> Option<String> {
+    let (Value::Object(expected), Value::Object(actual)) = (expected, actual) else {
+        return (expected != actual).then_some(pointer);
+    };
+
+    expected
+        .keys()
+        .chain(actual.keys().filter(|name| !expected.contains_key(*name)))
+        .find_map(|name| {
+            let pointer = to_pointer(&pointer, name);
+
+            match (expected.get(name), actual.get(name)) {
+                (Some(expected), Some(actual)) => mismatched_member(expected, actual, pointer),
+                _ => Some(pointer),
+            }
+        })
+}
+
+/// Emits a JSON Merge Patch ([RFC 7386](https://www.rfc-editor.org/rfc/rfc7386)) document from a partial.
+///
+/// [`None`] (and `Patch::Missing`) values are omitted, `Patch::Null` values are emitted as `null`, and
+/// `nested` fields are emitted as nested objects. Any other value (including maps, and structs of fields that don't
+/// use the `nested` field option) is emitted as a whole. Any serde renames of the partial are used as member names.
+///
+/// Note: See [`to_json_patch`] for when `nested` and `patch` fields are recognized.
+pub fn to_merge_patch<T: Serialize + ?Sized>(partial: &T) -> Result<Value, serde_json::Error> {
+    let mut patch = Value::Null;
+
+    for (pointer, value) in to_members(partial)? {
+        // partials are objects, even when none of their members are present
+        let value = value.unwrap_or_else(|| Value::Object(Map::new()));
+
+        match pointer_member(&mut patch, &pointer) {
+            Some(member) => *member = value,
+            None => patch = value,
+        }
+    }
+
+    Ok(patch)
+}
+
+/// Applies a JSON Merge Patch ([RFC 7386](https://www.rfc-editor.org/rfc/rfc7386)) document to a JSON value.
+pub fn apply_merge_patch(target: &mut Value, patch: Value) {
+    let Value::Object(patch) = patch else {
+        *target = patch;
+        return;
+    };
+
+    if !target.is_object() {
+        *target = Value::Object(Map::new());
+    }
+
+    let Value::Object(target) = target else {
+        unreachable!("target was replaced with an object");
+    };
+
+    for (name, value) in patch {
+        if value.is_null() {
+            target.remove(&name);
+        } else {
+            apply_merge_patch(target.entry(name).or_insert(Value::Null), value);
+        }
+    }
+}
+
+/// A JSON Patch ([RFC 6902](https://www.rfc-editor.org/rfc/rfc6902)) operation.
+///
+/// Note: `path` and `from` are JSON Pointers ([RFC 6901](https://www.rfc-editor.org/rfc/rfc6901)).
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "op", rename_all = "lowercase")]
+pub enum PatchOperation {
+    /// Adds `value` at `path`, replacing any existing member.
+    Add { path: String, value: Value },
+    /// Removes the value at `path`.
+    Remove { path: String },
+    /// Replaces the value at `path` with `value`.
+    Replace { path: String, value: Value },
+    /// Moves the value at `from` to `path`.
+    Move { from: String, path: String },
+    /// Copies the value at `from` to `path`.
+    Copy { from: String, path: String },
+    /// Tests that the value at `path` is equal to `value`.
+    Test { path: String, value: Value },
+}
+
+impl PatchOperation {
+    /// Gets the name of the operation, as used by the `op` member.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Add { .. } => "add",
+            Self::Remove { .. } => "remove",
+            Self::Replace { .. } => "replace",
+            Self::Move { .. } => "move",
+            Self::Copy { .. } => "copy",
+            Self::Test { .. } => "test",
+        }
+    }
+}
+
+/// The error returned when a JSON Patch can't be applied through a [`Partial`] implementation.
+#[derive(Debug)]
+pub enum JsonPatchError {
+    /// The operation isn't supported, as it depends on the current value (`move`, `copy` and `test`).
+    UnsupportedOperation(&'static str),
+    /// The path doesn't refer to a member of an object, refers to a member of a value set by a previous operation, or
+    /// refers to a member the partial can't represent (such as a `remove` of a field without the `patch` field option).
+    InvalidPath(String),
+    /// The resulting partial couldn't be deserialized.
+    Json(serde_json::Error),
+}
+
+impl fmt::Display for JsonPatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedOperation(op) => write!(f, "operation `{}` is not supported", op),
+            Self::InvalidPath(path) => write!(f, "path `{}` is not supported", path),
+            Self::Json(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for JsonPatchError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Json(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<serde_json::Error> for JsonPatchError {
+    fn from(value: serde_json::Error) -> Self {
+        Self::Json(value)
+    }
+}
+
+/// Emits JSON Patch ([RFC 6902](https://www.rfc-editor.org/rfc/rfc6902)) operations from a partial.
+///
+/// Values are emitted as `add` operations (which replace any existing member), and `Patch::Null` values as `remove`
+/// operations, with paths derived from the (serde renamed) member names. `nested` fields are emitted as operations on
+/// their own members, while any other value (including maps, and structs of fields that don't use the `nested` field
+/// option) is emitted as a whole.
+///
+/// Note: `nested` and `patch` fields are only recognized when the partial uses the `json` struct option, and the
+/// partial of a `nested` field must be a struct with named fields.
+pub fn to_json_patch<T: Serialize + ?Sized>(
+    partial: &T,
+) -> Result<Vec<PatchOperation>, serde_json::Error> {
+    let ops = to_members(partial)?
+        .into_iter()
+        .filter_map(|(path, value)| match value? {
+            Value::Null => Some(PatchOperation::Remove { path }),
+            value => Some(PatchOperation::Add { path, value }),
+        })
+        .collect();
+
+    Ok(ops)
+}
+
+/// Emits JSON Patch ([RFC 6902](https://www.rfc-editor.org/rfc/rfc6902)) operations that turn `old` into `new`.
+///
+/// Note: See [`to_json_patch`], which is used with the result of [`Diff::diff`].
+pub fn diff_json_patch<T>(old: &T, new: &T) -> Result<Vec<PatchOperation>, serde_json::Error>
+where
+    T: Diff,
+    T::Item: Serialize,
+{
+    to_json_patch(&old.diff(new))
+}
+
+/// Applies JSON Patch ([RFC 6902](https://www.rfc-editor.org/rfc/rfc6902)) operations to `target` through its
+/// [`Partial`] implementation, returning the result of [`Partial::apply_some`].
+///
+/// The operations are collected into a single partial, where `add` and `replace` operations set values and `remove`
+/// operations set `Patch::Null` values (see [`from_merge_patch`]). Later operations take precedence. Operations the
+/// partial can't represent are rejected with [`JsonPatchError::InvalidPath`], such as a `remove` of a field that
+/// doesn't use the `patch` field option, as it can't be removed.
+///
+/// Note: Each path segment refers to a member of an object, as the operations can't refer to array elements.
+/// `move`, `copy` and `test` operations aren't supported, as they depend on the current value.
+pub fn apply_json_patch<T, I>(target: &mut T, ops: I) -> Result<bool, JsonPatchError>
+where
+    T: Partial,
+    T::Item: Serialize + DeserializeOwned,
+    I: IntoIterator<Item = PatchOperation>,
+{
+    let mut patch = Value::Object(Map::new());
+
+    for op in ops {
+        let (path, value) = match op {
+            PatchOperation::Add { path, value } | PatchOperation::Replace { path, value } => {
+                (path, value)
+            }
+            PatchOperation::Remove { path } => (path, Value::Null),
+            op => return Err(JsonPatchError::UnsupportedOperation(op.name())),
+        };
+
+        let member = pointer_member(&mut patch, &path)
+            .ok_or_else(|| JsonPatchError::InvalidPath(path.clone()))?;
+        *member = value;
+    }
+
+    // see `from_merge_patch`, where members the partial can't represent are paths it doesn't support
+    let partial = T::Item::deserialize(&patch)?;
+    if let Some(pointer) = mismatched_member(&patch, &to_merge_patch(&partial)?, String::new()) {
+        return Err(JsonPatchError::InvalidPath(pointer));
+    }
+
+    Ok(target.apply_some(partial))
+}
+
+/// A present member of a partial, as its JSON Pointer and value - or [`None`] for a partial, whose members follow.
+type Member = (String, Option<Value>);
+
+/// Gets the present members of `partial`, recursing into `nested` fields.
+fn to_members<T: Serialize + ?Sized>(partial: &T) -> Result<Vec<Member>, serde_json::Error> {
+    let mut members = Vec::new();
+
+    push_partial(&mut members, String::new(), partial)?;
+
+    Ok(members)
+}
+
+/// Pushes the members of `partial`, or the whole value when it isn't a struct.
+fn push_partial<T: Serialize + ?Sized>(
+    members: &mut Vec<Member>,
+    pointer: String,
+    partial: &T,
+) -> Result<(), serde_json::Error> {
+    if !partial.serialize(MemberSerializer {
+        members,
+        pointer: pointer.clone(),
+        is_partial: true,
+        is_patch: false,
+    })? {
+        members.push((pointer, Some(serde_json::to_value(partial)?)));
+    }
+
+    Ok(())
+}
+
+/// Appends the member `name` to the JSON Pointer `prefix`, escaping `~` and `/`.
+fn to_pointer(prefix: &str, name: &str) -> String {
+    let mut pointer = String::from(prefix);
+    pointer.push('/');
+    pointer.push_str(&name.replace('~', "~0").replace('/', "~1"));
+
+    pointer
+}
+
+/// Gets the member `pointer` refers to, inserting objects for each missing parent.
+///
+/// Note: Returns [`None`] for the root, or when a parent isn't an object.
+fn pointer_member<'a>(patch: &'a mut Value, pointer: &str) -> Option<&'a mut Value> {
+    let mut segments = pointer.strip_prefix('/')?.split('/').peekable();
+    let mut value = patch;
+
+    while let Some(segment) = segments.next() {
+        let name = segment.replace("~1", "/").replace("~0", "~");
+        let members = value.as_object_mut()?;
+
+        value = if segments.peek().is_some() {
+            members
+                .entry(name)
+                .or_insert_with(|| Value::Object(Map::new()))
+        } else {
+            members.entry(name).or_insert(Value::Null)
+        };
+    }
+
+    Some(value)
+}
+
+/// A [`ser::Serializer`] that pushes the members of a partial (or of a member of a partial), evaluating to whether
+/// they were pushed. Otherwise, the caller pushes the whole value.
+///
+/// Note: Only structs are partials, and only `nested` fields (marked by `__private::serialize_nested`, with the `json`
+/// option) of a partial are partials themselves. `patch` fields are marked by `__private::serialize_patch`.
+struct MemberSerializer<'a> {
+    members: &'a mut Vec<Member>,
+    pointer: String,
+    is_partial: bool,
+    is_patch: bool,
+}
+
+macro_rules! serialize_as_whole {
+    ($($method:ident($ty:ty)),* $(,)?) => {
+        $(
+            fn $method(self, _v: $ty) -> Result<Self::Ok, Self::Error> {
+                Ok(false)
+            }
+        )*
+    };
+}
+
+impl<'a> ser::Serializer for MemberSerializer<'a> {
+    type Ok = bool;
+    type Error = serde_json::Error;
+
+    type SerializeSeq = SerializeWhole;
+    type SerializeTuple = SerializeWhole;
+    type SerializeTupleStruct = SerializeWhole;
+    type SerializeTupleVariant = SerializeWhole;
+    type SerializeMap = SerializeWhole;
+    type SerializeStruct = SerializeMembers<'a>;
+    type SerializeStructVariant = SerializeWhole;
+
+    serialize_as_whole! {
+        serialize_bool(bool),
+        serialize_i8(i8),
+        serialize_i16(i16),
+        serialize_i32(i32),
+        serialize_i64(i64),
+        serialize_i128(i128),
+        serialize_u8(u8),
+        serialize_u16(u16),
+        serialize_u32(u32),
+        serialize_u64(u64),
+        serialize_u128(u128),
+        serialize_f32(f32),
+        serialize_f64(f64),
+        serialize_char(char),
+        serialize_str(&str),
+        serialize_bytes(&[u8]),
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        // absent values are omitted, while `Patch::Null` values are emitted as a whole
+        Ok(!self.is_patch)
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Self::Ok, Self::Error> {
+        if !self.is_partial {
+            return Ok(false);
+        }
+
+        push_partial(self.members, self.pointer, value)?;
+
+        Ok(true)
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        Ok(false)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, Self::Error> {
+        Ok(false)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        Ok(false)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        if name == crate::__private::PATCH_NAME {
+            return value.serialize(MemberSerializer {
+                is_patch: true,
+                ..self
+            });
+        }
+
+        if name != crate::__private::NESTED_NAME {
+            return value.serialize(self);
+        }
+
+        // the value of a `nested` field is an `Option` of the nested partial
+        value.serialize(MemberSerializer {
+            is_partial: true,
+            ..self
+        })
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        Ok(false)
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Ok(SerializeWhole)
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        Ok(SerializeWhole)
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        Ok(SerializeWhole)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        Ok(SerializeWhole)
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Ok(SerializeWhole)
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        if !self.is_partial {
+            return Ok(SerializeMembers {
+                members: None,
+                pointer: self.pointer,
+            });
+        }
+
+        self.members.push((self.pointer.clone(), None));
+
+        Ok(SerializeMembers {
+            members: Some(self.members),
+            pointer: self.pointer,
+        })
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        Ok(SerializeWhole)
+    }
+}
+
+/// Pushes each member of a partial, or ignores the members of any other struct.
+struct SerializeMembers<'a> {
+    members: Option<&'a mut Vec<Member>>,
+    pointer: String,
+}
+
+impl<'a> ser::SerializeStruct for SerializeMembers<'a> {
+    type Ok = bool;
+    type Error = serde_json::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        let Some(members) = self.members.as_deref_mut() else {
+            return Ok(());
+        };
+
+        let pointer = to_pointer(&self.pointer, key);
+        if !value.serialize(MemberSerializer {
+            members,
+            pointer: pointer.clone(),
+            is_partial: false,
+            is_patch: false,
+        })? {
+            members.push((pointer, Some(serde_json::to_value(value)?)));
+        }
+
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(self.members.is_some())
+    }
+}
+
+/// Ignores the contents of a value, which is emitted as a whole.
+struct SerializeWhole;
+
+impl ser::SerializeSeq for SerializeWhole {
+    type Ok = bool;
+    type Error = serde_json::Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, _value: &T) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(false)
+    }
+}
+
+impl ser::SerializeTuple for SerializeWhole {
+    type Ok = bool;
+    type Error = serde_json::Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, _value: &T) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(false)
+    }
+}
+
+impl ser::SerializeTupleStruct for SerializeWhole {
+    type Ok = bool;
+    type Error = serde_json::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, _value: &T) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(false)
+    }
+}
+
+impl ser::SerializeTupleVariant for SerializeWhole {
+    type Ok = bool;
+    type Error = serde_json::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, _value: &T) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(false)
+    }
+}
+
+impl ser::SerializeMap for SerializeWhole {
+    type Ok = bool;
+    type Error = serde_json::Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, _key: &T) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, _value: &T) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(false)
+    }
+}
+
+impl ser::SerializeStructVariant for SerializeWhole {
+    type Ok = bool;
+    type Error = serde_json::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        _key: &'static str,
+        _value: &T,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(false)
+    }
+}
@@ -0,0 +1,156 @@
+use alloc::{string::String, vec::Vec};
+use core::fmt;
+
+use crate::{FieldNames, Merge, Partial};
+
+/// A stack of named partials (layers) that are applied in order, such that later layers take priority over
+/// earlier ones - for example, defaults, a file, the environment and command line flags.
+///
+/// Note: With [`FieldNames`], [`Layers::origin`] gets the layer that set a given field (or a field of a `nested` field).
+pub struct Layers<T: Partial> {
+    layers: Vec<(String, T::Item)>,
+}
+
+impl<T: Partial> Layers<T> {
+    /// Creates a new [`Layers`], without any layers.
+    pub const fn new() -> Self {
+        Self { layers: Vec::new() }
+    }
+
+    /// Adds the layer `partial` named `name`, with a higher priority than any existing layer.
+    pub fn push(&mut self, name: impl Into<String>, partial: T::Item) {
+        self.layers.push((name.into(), partial));
+    }
+
+    /// Adds the layer `partial` named `name`, with a higher priority than any existing layer, returning the layers.
+    pub fn with(mut self, name: impl Into<String>, partial: T::Item) -> Self {
+        self.push(name, partial);
+        self
+    }
+
+    /// Gets an iterator over the names and partials of the layers, from lowest to highest priority.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (&str, &T::Item)> + '_ {
+        self.layers
+            .iter()
+            .map(|(name, partial)| (name.as_str(), partial))
+    }
+
+    /// Gets the partial of the layer named `name`.
+    ///
+    /// Note: When multiple layers are named `name`, the one with the highest priority is used.
+    pub fn get(&self, name: &str) -> Option<&T::Item> {
+        self.iter()
+            .rev()
+            .find(|(layer, _)| *layer == name)
+            .map(|(_, partial)| partial)
+    }
+
+    /// Gets the number of layers.
+    pub fn len(&self) -> usize {
+        self.layers.len()
+    }
+
+    /// Gets whether there are no layers.
+    pub fn is_empty(&self) -> bool {
+        self.layers.is_empty()
+    }
+
+    /// Applies each layer to `base` in order, returning the result.
+    pub fn resolve(self, mut base: T) -> T {
+        for (_, partial) in self.layers {
+            base.apply_some(partial);
+        }
+
+        base
+    }
+
+    /// Merges the layers into a single partial, where values from later layers replace earlier ones.
+    ///
+    /// Note: This uses [`Merge::overlay`] (see `merge`), such that `nested` fields set by multiple layers are merged,
+    /// rather than replaced.
+    pub fn merged(self) -> T::Item
+    where
+        T::Item: Merge + Default,
+    {
+        self.layers
+            .into_iter()
+            .fold(T::Item::default(), |merged, (_, partial)| {
+                merged.overlay(partial)
+            })
+    }
+
+    /// Converts the [`Layers::merged`] partial into a `T`, without a base value.
+    ///
+    /// Note: This uses `T`'s [`TryFrom`] implementation, which usually fails when no layer sets a field.
+    pub fn try_resolve(self) -> Result<T, <T as TryFrom<T::Item>>::Error>
+    where
+        T: TryFrom<T::Item>,
+        T::Item: Merge + Default,
+    {
+        T::try_from(self.merged())
+    }
+
+    /// Gets the name of the layer with the highest priority that sets the field at `path`, which is a field name, or
+    /// a path into a `nested` field (such as `server.port`, see [`FieldNames::path_is_some`]).
+    ///
+    /// Note: Returns [`None`] when no layer sets the field, or there is no field at `path`.
+    pub fn origin(&self, path: &str) -> Option<&str>
+    where
+        T::Item: FieldNames,
+    {
+        self.iter()
+            .rev()
+            .find(|(_, partial)| partial.path_is_some(path))
+            .map(|(name, _)| name)
+    }
+
+    /// Gets an iterator over each field name, and the name of the layer that sets it (see [`Layers::origin`]).
+    pub fn origins(&self) -> impl Iterator<Item = (&'static str, Option<&str>)> + '_
+    where
+        T::Item: FieldNames,
+    {
+        T::Item::FIELD_NAMES
+            .iter()
+            .map(move |field| (*field, self.origin(field)))
+    }
+}
+
+// note: these can't be derived, as the derived bounds don't include `T::Item`
+impl<T: Partial> fmt::Debug for Layers<T>
+where
+    T::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Layers")
+            .field("layers", &self.layers)
+            .finish()
+    }
+}
+
+impl<T: Partial> Clone for Layers<T>
+where
+    T::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            layers: self.layers.clone(),
+        }
+    }
+}
+
+impl<T: Partial> Default for Layers<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Partial, N: Into<String>> FromIterator<(N, T::Item)> for Layers<T> {
+    fn from_iter<I: IntoIterator<Item = (N, T::Item)>>(iter: I) -> Self {
+        Self {
+            layers: iter
+                .into_iter()
+                .map(|(name, partial)| (name.into(), partial))
+                .collect(),
+        }
+    }
+}
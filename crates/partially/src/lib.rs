@@ -3,42 +3,249 @@
 // include our readme docs
 #![doc = include_str!(concat!("../", env!("CARGO_PKG_README")))]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "alloc")]
+mod env;
+mod field_names;
+mod impls;
+#[cfg(feature = "serde_json")]
+pub mod json;
+#[cfg(feature = "alloc")]
+mod layers;
+#[cfg(feature = "alloc")]
+mod map_patch;
+mod merge;
+mod missing_fields;
+mod patch;
+#[cfg(feature = "alloc")]
+mod set_field;
+mod variant;
+#[cfg(feature = "alloc")]
+mod vec_patch;
+
+#[cfg(feature = "alloc")]
+pub use env::EnvError;
+pub use field_names::SomeFieldNames;
+pub use impls::PartialOption;
+#[cfg(feature = "alloc")]
+pub use layers::Layers;
+#[cfg(feature = "alloc")]
+pub use map_patch::{MapOp, MapPatch};
+pub use merge::{Merge, MergeConflict, MergePolicy};
+pub use missing_fields::{InvalidField, MissingFields, TryFromPartialError};
+pub use patch::Patch;
+#[cfg(feature = "alloc")]
+pub use set_field::FieldError;
+pub use variant::VariantError;
+#[cfg(feature = "alloc")]
+pub use vec_patch::{VecOp, VecPatch, VecPatchError};
+
+/// Helpers used by the code generated by `partially_derive`.
+///
+/// Note: These are not part of the public API, and may change at any time.
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "alloc")]
+    pub use alloc::{boxed::Box, string::String};
+    pub use core::any::Any;
+
+    #[cfg(feature = "alloc")]
+    pub use crate::env::{env_name, env_nested_prefix, parse_env};
+    #[cfg(feature = "alloc")]
+    pub use crate::set_field::{set_option, set_patch, set_value, unknown};
+
+    /// The name of the newtype struct `nested` fields are serialized as, such that the `json` module can recognize them.
+    #[cfg(feature = "serde")]
+    pub const NESTED_NAME: &str = "__PartiallyNested";
+
+    /// Serializes a `nested` field as a newtype struct named [`NESTED_NAME`], which most formats (including JSON)
+    /// serialize as the value itself.
+    #[cfg(feature = "serde")]
+    pub fn serialize_nested<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: serde::Serialize,
+        S: serde::Serializer,
+    {
+        serializer.serialize_newtype_struct(NESTED_NAME, value)
+    }
+
+    /// The name of the newtype struct `patch` fields are serialized as, such that the `json` module can recognize them.
+    #[cfg(feature = "serde")]
+    pub const PATCH_NAME: &str = "__PartiallyPatch";
+
+    /// Serializes a `patch` field as a newtype struct named [`PATCH_NAME`], which most formats (including JSON)
+    /// serialize as the value itself.
+    #[cfg(feature = "serde")]
+    pub fn serialize_patch<T, S>(value: &crate::Patch<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: serde::Serialize,
+        S: serde::Serializer,
+    {
+        serializer.serialize_newtype_struct(PATCH_NAME, value)
+    }
+}
+
 /// ## partially_derive
 /// supports automatically generating a mirrored struct with each field wrapped in [`Option<T>`], and generates a `partially::Partial` implementation that allows applying the [`Some`] fields of the mirrored struct to the base struct.
 ///
+/// Tuple structs are also supported, generating a tuple struct with each field wrapped in [`Option<T>`], such as `PartialRgb(Option<u8>, Option<u8>, Option<u8>)` for `struct Rgb(u8, u8, u8)`. Fields are applied by their position, and `omit`-ted fields are left out of the generated struct, shifting the positions of the following fields. Where field names are used (such as by `field_names`, `set_field`, `env` and `try_from`), the position of the field in the generated struct is used as its name.
+///
+/// Enums are also supported, generating an enum whose variants mirror the base enum, with each field wrapped in [`Option<T>`]. Applying a partial of the current variant applies its present fields in place, like a struct. Applying a partial of a different variant replaces the value when each of its fields is present, filling `omit`-ted fields (and `transparent = "never"` fields) with their `default` expression, or `Default::default()`, and converting `nested` fields using their `TryFrom` implementation (see `try_from`, which is only required for enums with more than one variant). Otherwise, the partial is rejected - `apply_some` returns `false`, and the value is unchanged. To tell a rejected partial from one that applies nothing, use the generated `TryPartial` implementation, which returns a `VariantError` instead. Of the struct options, only `rename`, `derive`, `attribute`, `skip_attributes`, `crate`, `compare` and `serde_sparse` are supported for enums, and as `error` is not supported, the fields of variants can't use `try_into` or `validate`.
+///
 /// ## Struct Options
 /// ### derive
 /// > Usage example: `#[partially(derive(Debug, Default))]`.
+///
 /// Instructs the macro to generate a `#[derive(...)]` attribute on the generated struct.
 /// ### rename
 /// > Usage example: `#[partially(rename = "MyGeneratedStruct")]`.
+///
 /// Instructs the macro to use a given identifier for the generated struct. By default, `Partial<BaseStructName>` is used.
 /// ### attribute
 /// > Usage example: `#[partially(attribute(serde(rename_all = "PascalCase")))]`
+///
 /// Instructs the macro to add an additional attribute to the generated struct. By default, the attributes defined on the base struct are forwarded to the generated struct, unless the `skip_attributes` option is present.
 /// ### skip_attributes
 /// > Usage example: `#[partially(skip_attributes)]`.
+///
 /// Instructs the macro to skip forwarding attributes from the original struct to the generated struct. By default, all attributes that are present on the base struct are added to the generated struct.
 /// Note: When using this option with the `derive` option, the derive attribute **will still be added to the generated struct**.
 /// Note: When using this option with the `attribute` option, the specified attribute(s) **will still be added to the generated struct**.
 /// ### crate
 /// > Usage example: `#[partially(crate = "my_partially_crate")]`.
+///
 /// Instructs the macro to use a different base path for the `Partial` trait implementation. By default, `partially` is used. This can be useful if you've forked the `partially` crate.
+/// ### error
+/// > Usage example: `#[partially(error = "MyError")]`.
+///
+/// Instructs the macro to generate a [`TryPartial`] implementation, using the given type as the [`TryPartial::Error`] type. Every field is converted and validated before anything is applied, so when an error is returned the base struct is left unchanged. By default, no [`TryPartial`] implementation is generated.
+/// Note: `nested` fields are applied using their own [`TryPartial`] implementation, so their struct must also use `error` (with an error type that converts into this one using [`From`]), and must implement [`Clone`], as they're applied to a copy that replaces the field once nothing else can fail.
+/// ### diff
+/// > Usage example: `#[partially(diff)]`.
+///
+/// Instructs the macro to generate a [`Diff`] implementation, which computes the generated struct that turns one base struct into another. Fields that are equal are [`None`], and fields that differ are [`Some`], containing a clone of the new value. `nested` fields are diffed using their own [`Diff`] implementation. By default, no [`Diff`] implementation is generated.
+/// Note: When using this option, each field must implement [`PartialEq`] and [`Clone`], and the `as_type` field option is not supported. `transparent` fields hold a clone of the new value, except that [`Option<T>`] fields using the default `transparent` mode are [`None`] when equal - so a change from [`Some`] to [`None`] can't be expressed.
+/// ### merge
+/// > Usage example: `#[partially(merge)]`.
+///
+/// Instructs the macro to generate a [`Merge`] implementation for the generated struct, which combines two generated structs into one. Fields that are [`None`] in one struct are filled from the other, and fields that are [`Some`] in both are resolved with a [`MergePolicy`]. `nested` fields that are [`Some`] in both are merged using their own [`Merge`] implementation, prefixing the path of a [`MergeConflict`] with the name of the `nested` field (such as `server.port`, with the `alloc` feature). [`Merge::overlay`] merges like [`MergePolicy::RightWins`], without returning a [`Result`]. By default, no [`Merge`] implementation is generated.
+/// Note: When using this option, each field must implement [`PartialEq`].
+/// ### try_from
+/// > Usage example: `#[partially(try_from)]`.
+///
+/// Instructs the macro to generate a [`TryFrom<GeneratedStruct>`] implementation for the base struct. The conversion succeeds when every field (other than `omit`-ted and `transparent` fields) is [`Some`], and otherwise returns a [`TryFromPartialError::Missing`] error holding a [`MissingFields`] that lists the names of the [`None`] fields, as named in the generated struct. `omit`-ted fields are filled using their `default` field option, or [`Default::default()`]. By default, no [`TryFrom`] implementation is generated.
+/// Note: `nested` fields are converted using their own [`TryFrom`] implementation, and fields using `try_into` or `validate` are converted and validated. Fields that are [`Some`], but fail to convert or validate, return a [`TryFromPartialError::Invalid`] error holding an [`InvalidField`], with the name of the field and (with the `alloc` feature) the message of the underlying error, which must implement `Display`. At most 128 fields may be missing.
+/// ### report
+/// > Usage example: `#[partially(report)]`.
+///
+/// Instructs the macro to generate a [`PartialReport`] implementation for the base struct, along with a `{BaseStruct}Fields` set type (for example, `DataFields` for `Data`) used as the report. The set type has a constant for each field that is not `omit`-ted, named after the base struct field in uppercase (for example, `DataFields::PORT` for `port`). By default, no [`PartialReport`] implementation is generated.
+/// Note: At most 128 fields are supported. `nested` fields are reported when their own [`Partial::apply_some`] returns `true`, and fields using `try_into` or `validate` are only reported when they convert and validate successfully.
+/// ### compare
+/// > Usage example: `#[partially(compare)]`.
+///
+/// Instructs the macro to compare each value with the current value of the base struct field, such that only fields whose value actually changes are applied. [`Partial::apply_some`] (and [`TryPartial::try_apply_some`] or [`PartialReport::apply_some_report`], when generated) then returns `true` (or reports a field) only when a value changed. By default, every [`Some`] value is applied.
+/// Note: Each field must implement [`PartialEq`]. `nested` fields are applied using their own [`Partial`] implementation, so they are compared when their struct also uses `compare`.
+/// ### undo
+/// > Usage example: `#[partially(undo)]`.
+///
+/// Instructs the macro to generate a [`PartialUndo`] implementation for the base struct. [`PartialUndo::apply_some_with_undo`] applies a partial like [`Partial::apply_some`], and returns a partial holding the previous value of each field it replaced, such that applying the returned partial restores the base struct. By default, no [`PartialUndo`] implementation is generated.
+/// Note: `nested` fields must also use `undo`. Fields using `try_into` or `validate` are only recorded when they convert and validate successfully, and when using `compare`, only changed fields are recorded. `transparent` and `as_type` fields are not supported.
+/// ### serde_sparse
+/// > Usage example: `#[partially(serde_sparse)]`.
+///
+/// Instructs the macro to add `#[serde(default, skip_serializing_if = ..)]` to each generated field that can be absent, such that [`None`] values are not serialized, and missing keys are deserialized as [`None`]. This uses [`Option::is_none`], or [`Patch::is_missing`] for `patch` fields. By default, no `serde` attributes are added to the generated fields.
+/// Note: The generated struct must still derive `serde::Serialize` and `serde::Deserialize` (for example, with `derive`). Fields using `as_type` or `transparent` are only affected when their generated type is an [`Option`].
+/// ### field_names
+/// > Usage example: `#[partially(field_names)]`.
+///
+/// Instructs the macro to generate a [`FieldNames`] implementation for the generated struct. [`FieldNames::FIELD_NAMES`] holds the name of each field of the generated struct (using `rename`-d names, and skipping `omit`-ted fields), [`FieldNames::field_is_some`] checks whether the field at an index is present, [`FieldNames::path_is_some`] checks whether the field at a path (such as `server.port`, for the `port` field of the `nested` field `server`) is present, and [`FieldNames::some_field_names`] iterates the names of the present fields. By default, no [`FieldNames`] implementation is generated.
+/// Note: `nested` fields must also use `field_names`.
+/// Note: [`FieldNames`] is available in `no_std` environments, without the `alloc` feature.
+/// ### set_field
+/// > Usage example: `#[partially(set_field)]`.
+///
+/// Instructs the macro to generate a `SetField` implementation for the generated struct. `SetField::set_field` sets a field by its name (using `rename`-d names) to a `Box<dyn Any>` value, which must hold the type of the generated field, or the type it wraps - for example, a `T` or an `Option<T>` for an `Option<T>` field. Otherwise, a `FieldError` is returned, for unknown fields or mismatched types. By default, no `SetField` implementation is generated.
+/// Note: Requires the `alloc` feature, and that each field is `'static`. For `patch` fields, an `Option<T>` value of `None` sets `Patch::Null`.
+/// ### env
+/// > Usage example: `#[partially(env)]`.
+///
+/// Instructs the macro to generate a `FromEnv` implementation for the generated struct. `from_env` populates the generated struct from the environment variables named `{PREFIX}_{FIELD}` (using the upper case, `rename`-d field names), parsing each value with `FromStr`, while missing variables are left as [`None`]. `nested` fields use the variables named `{PREFIX}_{FIELD}__{NESTED_FIELD}`, and must also use `env`. `from_env_with` uses a lookup function rather than the process environment, for example in tests. By default, no `FromEnv` implementation is generated.
+/// Note: Requires the `alloc` feature (and `std` for `from_env`). Values that fail to parse return an `EnvError`, holding the name of the variable. `transparent` and `as_type` fields are only supported when their generated type is an `Option`.
+/// ### clap
+/// > Usage example: `#[partially(clap)]`.
+///
+/// Instructs the macro to derive `clap::Args` for the generated struct, and to add `#[arg(long)]` to each field, such that each field is an optional `--kebab-case` flag (using the `rename`-d field names), with the field's doc comments as its help text. `nested` fields are flattened into the generated struct using `#[command(flatten)]`, and must also use `clap`. The generated struct can then be parsed as part of a `clap` command, and applied with [`Partial::apply_some`]. By default, `clap::Args` is not derived.
+/// Note: Requires a dependency on `clap` (version 4.1 or later, which supports flattening `Option` fields), with the `derive` feature. `patch` fields and tuple structs are not supported, and `transparent` and `as_type` fields are only supported when their generated type is an `Option`.
+/// ### json
+/// > Usage example: `#[partially(json)]`.
+///
+/// Instructs the macro to serialize `nested` and `patch` fields of the generated struct as a newtype struct, such that the `json` module can recognize them - emitting the members of `nested` fields rather than the whole value, and [`Patch::Null`] values rather than omitting them. Most formats (including JSON) serialize a newtype struct as the value itself. By default, `nested` fields are serialized as is.
+/// Note: Requires the `serde` feature, and that the generated struct derives `serde::Serialize` (using the `derive` option).
+/// ### bound
+/// > Usage example: `#[partially(bound = "T: Clone")]`.
+///
+/// Instructs the macro to use the given where-clause predicates for the generated struct, and for the implementations for it (such as `Merge`), replacing the where-clause of the base struct - for example, to relax bounds the generated struct doesn't need. Implementations for the base struct (such as `Partial`) keep the where-clause of the base struct, as it's required for the base struct to be well-formed, extended with the given predicates. By default, the where-clause of the base struct is used.
+/// Note: Bounds declared inline on the generic parameters (such as `<T: Clone>`) are not replaced.
 ///
 /// ## Field Options
 /// ### rename
 /// > Usage example: `#[partially(rename = "new_field_name")]`.
+///
 /// Instructs the macro to use a given identifier for the generated field. By default, the same name as the base struct is used.
+///
+/// Note: Not supported for fields of tuple structs.
 /// ### omit
 /// > Usage example: `#[partially(omit)]`.
+///
 /// Instructs the macro to omit the field from the generated struct. By default, no fields are omitted.
+/// Note: When omitting fields leaves a generic type or lifetime parameter unused by the generated struct, a hidden `PhantomData` marker field is added to it (or a hidden, uninhabited variant for enums), so it still compiles. Generated implementations fill the marker, and struct literals can use `..Default::default()` or `core::marker::PhantomData`. The marker is skipped by `serde` (when the `derive` struct option includes `Serialize` or `Deserialize`) and by `clap`.
+/// ### default
+/// > Usage example: `#[partially(omit, default = "8080")]`.
+///
+/// Instructs the macro to use the given expression to fill an `omit`-ted field, when converting the generated struct into the base struct with the `try_from` struct option. By default, [`Default::default()`] is used.
 /// ### transparent
-/// > Usage example: `#[partially(transparent)]`.
-/// Instructs the macro to skip wrapping the generated field in [`Option<T>`], instead transparently mirroring the field type into the generated struct.
+/// > Usage example: `#[partially(transparent)]` or `#[partially(transparent = "never")]`.
+///
+/// Instructs the macro to skip wrapping the generated field in [`Option<T>`], instead transparently mirroring the field type into the generated struct. By default, a field with an [`Option<T>`] type is applied when it's [`Some`] (like any other field), while other fields always overwrite the base struct field. With `transparent = "always"`, the generated field always overwrites the base struct field, so [`Partial::apply_some`] always returns `true`. With `transparent = "never"`, the generated field is never applied to the base struct field.
+/// Note: Cannot be used with `try_into` or `validate`. When merging partials, [`Option<T>`] fields using the default mode are merged like any other field, while other `transparent` fields are resolved with the [`MergePolicy`], unless they use `transparent = "never"` - in which case the left value is kept.
 /// ### as_type
 /// > Usage example: `#[partially(as_type = "Option<f32>")]`.
+///
 /// Instructs the macro to use the provided type instead of [`Option<T>`] when generating the field. Note that the provided type will be used verbatim, so if you expect an [`Option<T>`] value, you'll need to manually specify that.
 /// Note: When using `as_type`, the given type must `Into<BaseType>` where `BaseType` is the original field type. This is required for `Partial` trait implementation.
+/// ### nested
+/// > Usage example: `#[partially(nested)]`.
+///
+/// Instructs the macro to use the field type's own [`Partial`] implementation, generating an `Option<<BaseType as Partial>::Item>` field rather than an [`Option<BaseType>`] field. When applied, [`Some`] values are applied to the base field with [`Partial::apply_some`], rather than overwriting it.
+/// Note: When applying a generated struct to another generated struct, `nested` fields are overwritten like any other field.
+/// Note: With the `json` struct option, `nested` fields are serialized as a newtype struct, such that the `json` module can recognize them.
+/// ### try_into
+/// > Usage example: `#[partially(as_type = "Option<String>", try_into)]`.
+///
+/// Instructs the macro to convert the generated field into the base field type with [`TryInto`], rather than [`Into`]. Requires the `error` struct option.
+/// Note: When using `try_into`, the type given to the `error` struct option must implement `From<E>`, where `E` is the conversion error type.
+/// Note: When using `try_into`, the `Partial` implementation skips values that fail conversion, rather than applying them.
+/// ### validate
+/// > Usage example: `#[partially(validate = "validate_port")]`.
+///
+/// Instructs the macro to call the given function with a reference to the (converted) field value before applying it. The function must have the signature `fn(&BaseType) -> Result<(), E>`. Requires the `error` struct option.
+/// Note: When using `validate`, the type given to the `error` struct option must implement `From<E>`, where `E` is the function error type.
+/// Note: When using `validate`, the `Partial` implementation skips values that fail validation, rather than applying them.
+/// ### patch
+/// > Usage example: `#[partially(patch)]`.
+///
+/// Instructs the macro to use [`Patch<T>`] for an [`Option<T>`] field, rather than `Option<Option<T>>`. [`Patch<T>`] distinguishes a missing value ([`Patch::Missing`], which isn't applied) from an explicit null ([`Patch::Null`], which applies [`None`]) and a value ([`Patch::Value`], which applies [`Some`]).
+/// Note: With the `serde` feature, [`Patch<T>`] deserializes `null` as [`Patch::Null`], and any other value as [`Patch::Value`]. When the generated struct derives `Deserialize` (using the `derive` option), `#[serde(default)]` is added to the generated field, such that a missing key is deserialized as [`Patch::Missing`]. When it derives `Serialize`, [`Patch::Missing`] values are skipped, while [`Patch::Null`] values are serialized as `null`.
+/// Note: `patch` can't be used with the `transparent`, `as_type`, `nested`, `try_into` or `validate` options.
+/// ### bound
+/// > Usage example: `#[partially(nested, bound = "T: Partial")]`.
+///
+/// Instructs the macro to add the given where-clause predicates to the generated struct, and to each generated implementation - for example, `T: Partial` for a `nested` field of a generic type `T`. By default, no predicates are added.
 ///
 /// ## Example
 /// ```
@@ -49,22 +256,22 @@
 /// struct Data {
 ///     value: String,
 /// }
-
+///
 /// let empty_partial = PartialData::default();
 /// let full_partial = PartialData {
 ///     value: Some("modified".to_string()),
 /// };
-
+///
 /// let mut full = Data {
 ///     value: "initial".to_string(),
 /// };
-
+///
 /// full.apply_some(empty_partial);
-
+///
 /// assert_eq!(full.value, "initial".to_string());
-
+///
 /// full.apply_some(full_partial);
-
+///
 /// assert_eq!(full.value, "modified".to_string());
 ///
 /// ```
@@ -82,3 +289,125 @@ pub trait Partial {
     /// Note: [`None`] values should not be applied.
     fn apply_some(&mut self, partial: Self::Item) -> bool;
 }
+
+/// Allows attempting to apply a [`TryPartial::Item`] to `Self`, where [`TryPartial::Item`] has [`Some`] values,
+/// and where applying those values may fail.
+pub trait TryPartial {
+    /// The type of the partial structure, that may have [`Some`] values.
+    type Item;
+
+    /// The type of error that may occur when applying [`TryPartial::Item`].
+    type Error;
+
+    /// Attempts to apply [`Some`] values from [`TryPartial::Item`] to [`self`], returning `Ok(true)`
+    /// when updates were made, and `Ok(false)` when nothing was applied.
+    ///
+    /// Note: [`None`] values should not be applied.
+    ///
+    /// Note: When an error is returned, [`self`] should be left unchanged.
+    fn try_apply_some(&mut self, partial: Self::Item) -> Result<bool, Self::Error>;
+}
+
+/// Allows computing the [`Partial::Item`] that turns `Self` into another `Self`.
+pub trait Diff: Partial {
+    /// Computes a [`Partial::Item`] with [`Some`] values for each field that differs between [`self`]
+    /// and `other`, such that applying it to [`self`] results in a value equal to `other`.
+    ///
+    /// Note: Fields that are equal should be [`None`].
+    fn diff(&self, other: &Self) -> Self::Item;
+}
+
+/// Allows applying a [`Partial::Item`] to `Self`, reporting exactly which fields were applied.
+pub trait PartialReport: Partial {
+    /// The type describing the set of fields that were applied.
+    type Report;
+
+    /// Applies [`Some`] values from [`Partial::Item`] to [`self`], returning a [`PartialReport::Report`]
+    /// containing each field that was applied.
+    ///
+    /// Note: [`None`] values should not be applied, and should not be reported.
+    fn apply_some_report(&mut self, partial: Self::Item) -> Self::Report;
+}
+
+/// Allows applying a [`Partial::Item`] to `Self`, while recording the values it replaced.
+pub trait PartialUndo: Partial {
+    /// Applies [`Some`] values from [`Partial::Item`] to [`self`], returning a [`Partial::Item`] with [`Some`]
+    /// values for each field that was applied, holding the previous value of that field.
+    ///
+    /// Note: Applying the returned [`Partial::Item`] should restore [`self`] to its previous state.
+    fn apply_some_with_undo(&mut self, partial: Self::Item) -> Self::Item;
+}
+
+/// Allows setting the fields of a partial by name, with dynamically typed values.
+#[cfg(feature = "alloc")]
+pub trait SetField {
+    /// Sets the field named `name` to `value`.
+    ///
+    /// Note: `value` must be of the type of the field, or the type it wraps (for example, `T` for an
+    /// [`Option<T>`] field). Otherwise, [`FieldError::TypeMismatch`] is returned, and [`self`] is left unchanged.
+    fn set_field(
+        &mut self,
+        name: &str,
+        value: alloc::boxed::Box<dyn core::any::Any>,
+    ) -> Result<(), FieldError>;
+}
+
+/// Allows populating a partial from environment variables, named `{PREFIX}_{FIELD}`.
+#[cfg(feature = "alloc")]
+pub trait FromEnv: Sized {
+    /// Populates `Self` from the variables that `lookup` returns a value for, returning whether any variable
+    /// was found.
+    ///
+    /// Note: Variables that are not found should be [`None`], and variables that can't be parsed should
+    /// return an [`EnvError`].
+    fn from_env_lookup(
+        prefix: &str,
+        lookup: &mut dyn FnMut(&str) -> Option<alloc::string::String>,
+    ) -> Result<(Self, bool), EnvError>;
+
+    /// Populates `Self` from the variables that `lookup` returns a value for.
+    ///
+    /// Note: This allows using a source other than the process environment, for example in tests.
+    fn from_env_with<F>(prefix: &str, mut lookup: F) -> Result<Self, EnvError>
+    where
+        F: FnMut(&str) -> Option<alloc::string::String>,
+    {
+        Self::from_env_lookup(prefix, &mut lookup).map(|(partial, _)| partial)
+    }
+
+    /// Populates `Self` from the variables of the process environment.
+    ///
+    /// Note: Variables that are not valid unicode are treated as missing.
+    #[cfg(feature = "std")]
+    fn from_env(prefix: &str) -> Result<Self, EnvError> {
+        Self::from_env_with(prefix, |name| std::env::var(name).ok())
+    }
+}
+
+/// Allows inspecting the names of the fields of a partial, and which of them are present.
+pub trait FieldNames {
+    /// The names of the fields, in declaration order.
+    const FIELD_NAMES: &'static [&'static str];
+
+    /// Gets whether the field at `index` (into [`FieldNames::FIELD_NAMES`]) is present.
+    ///
+    /// Note: Indices that are out of bounds should return `false`.
+    fn field_is_some(&self, index: usize) -> bool;
+
+    /// Gets whether the field at `path` is present, where `path` is a field name, or the name of a `nested` field
+    /// followed by `.` and a path into its partial (such as `server.port`).
+    ///
+    /// Note: Paths that don't refer to a field should return `false`. By default, `path` is only matched against
+    /// [`FieldNames::FIELD_NAMES`].
+    fn path_is_some(&self, path: &str) -> bool {
+        Self::FIELD_NAMES
+            .iter()
+            .position(|name| *name == path)
+            .is_some_and(|index| self.field_is_some(index))
+    }
+
+    /// Gets an iterator over the names of the fields that are present.
+    fn some_field_names(&self) -> SomeFieldNames<'_, Self> {
+        SomeFieldNames::new(self)
+    }
+}
@@ -0,0 +1,281 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+use core::fmt;
+
+#[cfg(feature = "std")]
+use core::hash::{BuildHasher, Hash};
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+use crate::Partial;
+
+/// A single operation of a [`MapPatch`], applied to the entry of a given key.
+///
+/// Note: The values must implement [`Partial`] for [`MapOp::Patch`], so maps of other values (such as
+/// `HashMap<String, String>`) can't be patched. Instead, their fields can omit the `nested` field option, replacing
+/// the map entirely.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(
+        rename_all = "snake_case",
+        bound(
+            serialize = "V: serde::Serialize, V::Item: serde::Serialize",
+            deserialize = "V: serde::Deserialize<'de>, V::Item: serde::Deserialize<'de>"
+        )
+    )
+)]
+pub enum MapOp<V: Partial> {
+    /// Inserts the value, replacing any existing value.
+    Set(V),
+    /// Applies the partial to the existing value, using its [`Partial`] implementation.
+    ///
+    /// Note: When there is no existing value, nothing is applied.
+    Patch(V::Item),
+    /// Removes the existing value.
+    Remove,
+}
+
+/// A set of keyed [`MapOp`] operations that are applied to a map in order, allowing entries to be
+/// inserted, patched and removed without replacing the map entirely.
+///
+/// Note: To use [`MapPatch`] for a map field of a derived struct, use the `nested` field option.
+pub struct MapPatch<K, V: Partial> {
+    entries: Vec<(K, MapOp<V>)>,
+}
+
+impl<K, V: Partial> MapPatch<K, V> {
+    /// Creates a new [`MapPatch`], without any operations.
+    pub const fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+
+    /// Adds `op` for the entry of `key` to the end of the patch.
+    pub fn add(&mut self, key: K, op: MapOp<V>) {
+        self.entries.push((key, op));
+    }
+
+    /// Adds `op` for the entry of `key` to the end of the patch, returning the patch.
+    pub fn with(mut self, key: K, op: MapOp<V>) -> Self {
+        self.add(key, op);
+        self
+    }
+
+    /// Gets the keyed operations of the patch.
+    pub fn entries(&self) -> &[(K, MapOp<V>)] {
+        &self.entries
+    }
+
+    /// Gets whether the patch has no operations.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Converts the patch into its keyed operations.
+    pub fn into_entries(self) -> Vec<(K, MapOp<V>)> {
+        self.entries
+    }
+}
+
+// note: these can't be derived, as the derived bounds don't include `V::Item`
+impl<K: fmt::Debug, V: Partial> fmt::Debug for MapPatch<K, V>
+where
+    MapOp<V>: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MapPatch")
+            .field("entries", &self.entries)
+            .finish()
+    }
+}
+
+impl<K: Clone, V: Partial> Clone for MapPatch<K, V>
+where
+    MapOp<V>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            entries: self.entries.clone(),
+        }
+    }
+}
+
+impl<K: PartialEq, V: Partial> PartialEq for MapPatch<K, V>
+where
+    MapOp<V>: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.entries == other.entries
+    }
+}
+
+impl<K: Eq, V: Partial> Eq for MapPatch<K, V> where MapOp<V>: Eq {}
+
+impl<K, V: Partial> Default for MapPatch<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V: Partial> From<Vec<(K, MapOp<V>)>> for MapPatch<K, V> {
+    fn from(entries: Vec<(K, MapOp<V>)>) -> Self {
+        Self { entries }
+    }
+}
+
+impl<K, V: Partial> FromIterator<(K, MapOp<V>)> for MapPatch<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, MapOp<V>)>>(iter: I) -> Self {
+        Self {
+            entries: iter.into_iter().collect(),
+        }
+    }
+}
+
+/// Applies each operation in order.
+///
+/// Note: [`MapOp::Patch`] and [`MapOp::Remove`] operations for keys that are not present are skipped.
+impl<K: Ord, V: Partial> Partial for BTreeMap<K, V> {
+    type Item = MapPatch<K, V>;
+
+    fn apply_some(&mut self, partial: Self::Item) -> bool {
+        apply_map_patch(self, partial)
+    }
+}
+
+impl<K: Ord, V> Map<K, V> for BTreeMap<K, V> {
+    fn insert(&mut self, key: K, value: V) {
+        BTreeMap::insert(self, key, value);
+    }
+
+    fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        BTreeMap::get_mut(self, key)
+    }
+
+    fn remove(&mut self, key: &K) -> bool {
+        BTreeMap::remove(self, key).is_some()
+    }
+}
+
+/// Applies each operation in order.
+///
+/// Note: [`MapOp::Patch`] and [`MapOp::Remove`] operations for keys that are not present are skipped.
+#[cfg(feature = "std")]
+impl<K: Eq + Hash, V: Partial, S: BuildHasher> Partial for HashMap<K, V, S> {
+    type Item = MapPatch<K, V>;
+
+    fn apply_some(&mut self, partial: Self::Item) -> bool {
+        apply_map_patch(self, partial)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: Eq + Hash, V, S: BuildHasher> Map<K, V> for HashMap<K, V, S> {
+    fn insert(&mut self, key: K, value: V) {
+        HashMap::insert(self, key, value);
+    }
+
+    fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        HashMap::get_mut(self, key)
+    }
+
+    fn remove(&mut self, key: &K) -> bool {
+        HashMap::remove(self, key).is_some()
+    }
+}
+
+/// The entry operations of a map, which a [`MapPatch`] is applied with.
+trait Map<K, V> {
+    /// Inserts `value` for `key`, replacing any existing value.
+    fn insert(&mut self, key: K, value: V);
+
+    /// Gets the existing value of `key`.
+    fn get_mut(&mut self, key: &K) -> Option<&mut V>;
+
+    /// Removes the existing value of `key`, returning whether it was present.
+    fn remove(&mut self, key: &K) -> bool;
+}
+
+/// Applies each operation of `partial` to `map` in order, returning whether any were applied.
+fn apply_map_patch<K, V: Partial>(map: &mut impl Map<K, V>, partial: MapPatch<K, V>) -> bool {
+    let mut will_apply_some = false;
+
+    for (key, op) in partial.entries {
+        will_apply_some |= match op {
+            MapOp::Set(value) => {
+                map.insert(key, value);
+                true
+            }
+            MapOp::Patch(partial) => map
+                .get_mut(&key)
+                .is_some_and(|value| value.apply_some(partial)),
+            MapOp::Remove => map.remove(&key),
+        };
+    }
+
+    will_apply_some
+}
+
+/// Serializes as a map, from each key to its operation.
+#[cfg(feature = "serde")]
+impl<K, V> serde::Serialize for MapPatch<K, V>
+where
+    K: serde::Serialize,
+    V: Partial + serde::Serialize,
+    V::Item: serde::Serialize,
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeMap;
+
+        let mut map = serializer.serialize_map(Some(self.entries.len()))?;
+
+        for (key, op) in &self.entries {
+            map.serialize_entry(key, op)?;
+        }
+
+        map.end()
+    }
+}
+
+/// Deserializes from a map, from each key to its operation, keeping the order of the entries.
+#[cfg(feature = "serde")]
+impl<'de, K, V> serde::Deserialize<'de> for MapPatch<K, V>
+where
+    K: serde::Deserialize<'de>,
+    V: Partial + serde::Deserialize<'de>,
+    V::Item: serde::Deserialize<'de>,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct MapPatchVisitor<K, V>(core::marker::PhantomData<(K, V)>);
+
+        impl<'de, K, V> serde::de::Visitor<'de> for MapPatchVisitor<K, V>
+        where
+            K: serde::Deserialize<'de>,
+            V: Partial + serde::Deserialize<'de>,
+            V::Item: serde::Deserialize<'de>,
+        {
+            type Value = MapPatch<K, V>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a map of keys to operations")
+            }
+
+            fn visit_map<A: serde::de::MapAccess<'de>>(
+                self,
+                mut access: A,
+            ) -> Result<Self::Value, A::Error> {
+                // the size hint isn't trusted, as it comes from the input
+                let mut entries = Vec::new();
+
+                while let Some(entry) = access.next_entry()? {
+                    entries.push(entry);
+                }
+
+                Ok(MapPatch { entries })
+            }
+        }
+
+        deserializer.deserialize_map(MapPatchVisitor(core::marker::PhantomData))
+    }
+}
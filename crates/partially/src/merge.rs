@@ -0,0 +1,107 @@
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use alloc::{format, string::String};
+
+/// Determines which value is kept when two partials being merged both have [`Some`] value for the same field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MergePolicy {
+    /// The value from the left (`self`) partial is kept.
+    LeftWins,
+    /// The value from the right (`other`) partial is kept.
+    RightWins,
+    /// A [`MergeConflict`] is returned, unless both values are equal.
+    ErrorOnConflict,
+}
+
+impl MergePolicy {
+    /// Resolves two [`Some`] values for the field named `field`, according to the policy.
+    pub fn resolve<T: PartialEq>(
+        self,
+        field: &'static str,
+        left: T,
+        right: T,
+    ) -> Result<T, MergeConflict> {
+        match self {
+            Self::LeftWins => Ok(left),
+            Self::RightWins => Ok(right),
+            Self::ErrorOnConflict if left == right => Ok(left),
+            Self::ErrorOnConflict => Err(MergeConflict::new(field)),
+        }
+    }
+}
+
+/// The error returned when merging with [`MergePolicy::ErrorOnConflict`], and both partials have
+/// different [`Some`] values for the same field.
+///
+/// Note: With the `alloc` feature, the conflict also holds the path to the field through `nested` fields, such as
+/// `server.port`. Otherwise, only the name of the conflicting field is known.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MergeConflict {
+    field: &'static str,
+    #[cfg(feature = "alloc")]
+    path: String,
+}
+
+impl MergeConflict {
+    /// Creates a new [`MergeConflict`] for the field named `field`.
+    pub fn new(field: &'static str) -> Self {
+        Self {
+            field,
+            #[cfg(feature = "alloc")]
+            path: String::from(field),
+        }
+    }
+
+    /// Prefixes the path of the conflict with `field`, the name of the `nested` field it occurred in.
+    ///
+    /// Note: Without the `alloc` feature, the conflict is returned unchanged.
+    pub fn nested(self, field: &'static str) -> Self {
+        #[cfg(feature = "alloc")]
+        let path = format!("{}.{}", field, self.path);
+        #[cfg(not(feature = "alloc"))]
+        let _ = field;
+
+        Self {
+            #[cfg(feature = "alloc")]
+            path,
+            ..self
+        }
+    }
+
+    /// Gets the name of the conflicting field.
+    pub fn field(&self) -> &'static str {
+        self.field
+    }
+
+    /// Gets the path to the conflicting field, with the names of the `nested` fields it occurred in separated by `.`.
+    #[cfg(feature = "alloc")]
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+}
+
+impl fmt::Display for MergeConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        #[cfg(feature = "alloc")]
+        let field = self.path.as_str();
+        #[cfg(not(feature = "alloc"))]
+        let field = self.field;
+
+        write!(f, "conflicting values for field `{}`", field)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MergeConflict {}
+
+/// Allows merging two partials into one, where [`None`] values are filled from the other partial,
+/// and [`Some`] values present in both partials are resolved with a [`MergePolicy`].
+pub trait Merge: Sized {
+    /// Merges `self` with `other`, using `policy` to resolve fields that are [`Some`] in both.
+    fn merge(self, other: Self, policy: MergePolicy) -> Result<Self, MergeConflict>;
+
+    /// Merges `self` with `other`, where [`Some`] values of `other` replace those of `self` - like [`Merge::merge`]
+    /// with [`MergePolicy::RightWins`], but without a [`Result`], as nothing can conflict.
+    fn overlay(self, other: Self) -> Self;
+}
@@ -0,0 +1,199 @@
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+
+/// The error returned when converting a partial into its base struct, and some of its fields are [`None`].
+///
+/// Note: The missing fields are recorded out of the fields that may be missing, which allows the error to be used
+/// without allocating. At most [`MissingFields::MAX_FIELDS`] fields may be missing.
+#[derive(Clone, Copy)]
+pub struct MissingFields {
+    fields: &'static [&'static str],
+    missing: u128,
+}
+
+impl MissingFields {
+    /// The maximum number of fields that may be missing.
+    pub const MAX_FIELDS: usize = 128;
+
+    /// Creates a new [`MissingFields`] for the names of the fields that may be missing, without any missing fields.
+    ///
+    /// Note: Fields beyond [`MissingFields::MAX_FIELDS`] are ignored.
+    pub const fn new(fields: &'static [&'static str]) -> Self {
+        Self { fields, missing: 0 }
+    }
+
+    /// Records the field named `field` as missing.
+    ///
+    /// Note: Fields that may not be missing are ignored.
+    pub fn push(&mut self, field: &'static str) {
+        if let Some(index) = self
+            .fields
+            .iter()
+            .take(Self::MAX_FIELDS)
+            .position(|name| *name == field)
+        {
+            self.missing |= 1 << index;
+        }
+    }
+
+    /// Gets an iterator over the names of the missing fields.
+    pub fn iter(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.fields
+            .iter()
+            .take(Self::MAX_FIELDS)
+            .enumerate()
+            .filter(|(index, _)| self.missing & (1 << index) != 0)
+            .map(|(_, field)| *field)
+    }
+
+    /// Gets whether the field named `field` is missing.
+    pub fn contains(&self, field: &str) -> bool {
+        self.iter().any(|missing| missing == field)
+    }
+
+    /// Gets the number of missing fields.
+    pub fn len(&self) -> usize {
+        self.missing.count_ones() as usize
+    }
+
+    /// Gets whether there are no missing fields.
+    pub fn is_empty(&self) -> bool {
+        self.missing == 0
+    }
+}
+
+impl PartialEq for MissingFields {
+    fn eq(&self, other: &Self) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl Eq for MissingFields {}
+
+impl fmt::Debug for MissingFields {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("MissingFields")
+            .field(&DebugList(self))
+            .finish()
+    }
+}
+
+/// Formats the names of the missing fields as a list.
+struct DebugList<'a>(&'a MissingFields);
+
+impl<'a> fmt::Debug for DebugList<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.0.iter()).finish()
+    }
+}
+
+impl fmt::Display for MissingFields {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "missing fields: ")?;
+
+        for (index, field) in self.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+
+            write!(f, "`{}`", field)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MissingFields {}
+
+/// The error returned when converting a partial into its base struct, and one of its fields is [`Some`], but its
+/// value fails to convert or validate.
+///
+/// Note: With the `alloc` feature, the error also holds the message of the underlying error. Otherwise, only the
+/// name of the field is known.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct InvalidField {
+    field: &'static str,
+    #[cfg(feature = "alloc")]
+    error: String,
+}
+
+impl InvalidField {
+    /// Creates a new [`InvalidField`] for the field named `field`, which failed with `error`.
+    pub fn new(field: &'static str, error: impl fmt::Display) -> Self {
+        #[cfg(not(feature = "alloc"))]
+        let _ = error;
+
+        Self {
+            field,
+            #[cfg(feature = "alloc")]
+            error: error.to_string(),
+        }
+    }
+
+    /// Gets the name of the invalid field.
+    pub fn field(&self) -> &'static str {
+        self.field
+    }
+
+    /// Gets the message of the underlying error.
+    #[cfg(feature = "alloc")]
+    pub fn error(&self) -> &str {
+        &self.error
+    }
+}
+
+impl fmt::Display for InvalidField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid field `{}`", self.field)?;
+
+        #[cfg(feature = "alloc")]
+        write!(f, ": {}", self.error)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidField {}
+
+/// The error returned when converting a partial into its base struct fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TryFromPartialError {
+    /// Some of the fields are [`None`].
+    Missing(MissingFields),
+    /// A field is [`Some`], but its value failed to convert or validate.
+    Invalid(InvalidField),
+}
+
+impl TryFromPartialError {
+    /// Gets the missing fields, when the error is [`TryFromPartialError::Missing`].
+    pub fn missing(&self) -> Option<&MissingFields> {
+        match self {
+            Self::Missing(missing) => Some(missing),
+            Self::Invalid(_) => None,
+        }
+    }
+
+    /// Gets the invalid field, when the error is [`TryFromPartialError::Invalid`].
+    pub fn invalid(&self) -> Option<&InvalidField> {
+        match self {
+            Self::Missing(_) => None,
+            Self::Invalid(invalid) => Some(invalid),
+        }
+    }
+}
+
+impl fmt::Display for TryFromPartialError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Missing(missing) => missing.fmt(f),
+            Self::Invalid(invalid) => invalid.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TryFromPartialError {}
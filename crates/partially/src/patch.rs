@@ -0,0 +1,94 @@
+/// A tri-state value, used by the `patch` field option to distinguish a missing value from an explicit null.
+///
+/// Note: With the `serde` feature, [`Patch::Null`] is deserialized from `null`, and [`Patch::Value`] from any
+/// other value. A missing value is deserialized as [`Patch::Missing`] when `#[serde(default)]` is used, which the
+/// `patch` field option adds when the generated struct derives `Deserialize`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Patch<T> {
+    /// No value is present, so nothing should be applied.
+    #[default]
+    Missing,
+    /// An explicit null value, so [`None`] should be applied.
+    Null,
+    /// A value, so [`Some`] value should be applied.
+    Value(T),
+}
+
+impl<T> Patch<T> {
+    /// Gets whether the patch is [`Patch::Missing`].
+    pub const fn is_missing(&self) -> bool {
+        matches!(self, Self::Missing)
+    }
+
+    /// Gets whether the patch is [`Patch::Null`].
+    pub const fn is_null(&self) -> bool {
+        matches!(self, Self::Null)
+    }
+
+    /// Gets whether the patch is [`Patch::Value`].
+    pub const fn is_value(&self) -> bool {
+        matches!(self, Self::Value(_))
+    }
+
+    /// Converts from `&Patch<T>` to `Patch<&T>`.
+    pub const fn as_ref(&self) -> Patch<&T> {
+        match self {
+            Self::Missing => Patch::Missing,
+            Self::Null => Patch::Null,
+            Self::Value(value) => Patch::Value(value),
+        }
+    }
+
+    /// Converts the patch into an [`Option<Option<T>>`], where [`Patch::Missing`] is [`None`], and
+    /// [`Patch::Null`] is `Some(None)`.
+    pub fn into_option(self) -> Option<Option<T>> {
+        match self {
+            Self::Missing => None,
+            Self::Null => Some(None),
+            Self::Value(value) => Some(Some(value)),
+        }
+    }
+}
+
+impl<T> From<Option<Option<T>>> for Patch<T> {
+    fn from(value: Option<Option<T>>) -> Self {
+        match value {
+            None => Self::Missing,
+            Some(None) => Self::Null,
+            Some(Some(value)) => Self::Value(value),
+        }
+    }
+}
+
+impl<T> From<Patch<T>> for Option<Option<T>> {
+    fn from(value: Patch<T>) -> Self {
+        value.into_option()
+    }
+}
+
+/// Serializes [`Patch::Value`] as its value, and both [`Patch::Null`] and [`Patch::Missing`] as none.
+///
+/// Note: Use `#[serde(skip_serializing_if = "Patch::is_missing")]` to omit [`Patch::Missing`] values, which the
+/// `patch` field option adds when the generated struct derives `Serialize`.
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for Patch<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Missing | Self::Null => serializer.serialize_none(),
+            Self::Value(value) => serializer.serialize_some(value),
+        }
+    }
+}
+
+/// Deserializes none as [`Patch::Null`], and any other value as [`Patch::Value`].
+///
+/// Note: Use `#[serde(default)]` to deserialize missing values as [`Patch::Missing`].
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for Patch<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Option::<T>::deserialize(deserializer).map(|value| match value {
+            None => Self::Null,
+            Some(value) => Self::Value(value),
+        })
+    }
+}
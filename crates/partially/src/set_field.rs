@@ -0,0 +1,109 @@
+use alloc::{boxed::Box, string::String};
+use core::{
+    any::{type_name, Any},
+    fmt,
+};
+
+use crate::Patch;
+
+/// The error returned when a field can't be set by name.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FieldError {
+    /// There is no field with the given name.
+    UnknownField(String),
+    /// The value is not of a type the field accepts.
+    TypeMismatch {
+        /// The name of the field.
+        field: &'static str,
+        /// The name of the type the field accepts.
+        expected: &'static str,
+    },
+}
+
+impl fmt::Display for FieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownField(field) => write!(f, "unknown field `{}`", field),
+            Self::TypeMismatch { field, expected } => write!(
+                f,
+                "field `{}` expected a value of type `{}`",
+                field, expected
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FieldError {}
+
+// note: the following are used by the `set_field` derive option, through `__private`
+
+/// Sets `field` to `value`, which must be a `T`.
+pub fn set_value<T: 'static>(
+    field: &mut T,
+    name: &'static str,
+    value: Box<dyn Any>,
+) -> Result<(), FieldError> {
+    match value.downcast::<T>() {
+        Ok(value) => {
+            *field = *value;
+            Ok(())
+        }
+        Err(_) => Err(mismatch::<T>(name)),
+    }
+}
+
+/// Sets `field` to `value`, which must be a `T` or an [`Option<T>`].
+pub fn set_option<T: 'static>(
+    field: &mut Option<T>,
+    name: &'static str,
+    value: Box<dyn Any>,
+) -> Result<(), FieldError> {
+    let value = match value.downcast::<T>() {
+        Ok(value) => {
+            *field = Some(*value);
+            return Ok(());
+        }
+        Err(value) => value,
+    };
+
+    set_value(field, name, value).map_err(|_| mismatch::<T>(name))
+}
+
+/// Sets `field` to `value`, which must be a `T`, an [`Option<T>`] (where [`None`] is [`Patch::Null`])
+/// or a [`Patch<T>`].
+pub fn set_patch<T: 'static>(
+    field: &mut Patch<T>,
+    name: &'static str,
+    value: Box<dyn Any>,
+) -> Result<(), FieldError> {
+    let value = match value.downcast::<Option<T>>() {
+        Ok(value) => {
+            *field = Patch::from(Some(*value));
+            return Ok(());
+        }
+        Err(value) => value,
+    };
+
+    let value = match value.downcast::<T>() {
+        Ok(value) => {
+            *field = Patch::Value(*value);
+            return Ok(());
+        }
+        Err(value) => value,
+    };
+
+    set_value(field, name, value).map_err(|_| mismatch::<T>(name))
+}
+
+/// Creates a [`FieldError::UnknownField`] for the field named `name`.
+pub fn unknown(name: &str) -> FieldError {
+    FieldError::UnknownField(name.into())
+}
+
+fn mismatch<T>(name: &'static str) -> FieldError {
+    FieldError::TypeMismatch {
+        field: name,
+        expected: type_name::<T>(),
+    }
+}
@@ -0,0 +1,24 @@
+use core::fmt;
+
+/// The error returned when applying a partial of a different variant of an enum, and the value can't be replaced
+/// with it, as some of its fields are [`None`] (or its `nested` fields fail conversion).
+///
+/// Note: Returned by the `TryPartial` implementation of a derived enum, where `Partial::apply_some` returns `false`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct VariantError {
+    /// The name of the variant of the partial.
+    pub variant: &'static str,
+}
+
+impl fmt::Display for VariantError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "cannot replace the value with variant `{}`, as the partial is missing fields, or they fail conversion",
+            self.variant
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for VariantError {}
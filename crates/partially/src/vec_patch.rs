@@ -0,0 +1,207 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::{Partial, TryPartial};
+
+/// A single operation of a [`VecPatch`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum VecOp<T> {
+    /// Appends `T` to the end of the [`Vec`].
+    Push(T),
+    /// Inserts `value` at `index`, shifting all values after it to the right.
+    Insert {
+        /// The index to insert at, which may be equal to the length of the [`Vec`].
+        index: usize,
+        /// The value to insert.
+        value: T,
+    },
+    /// Removes the value at the given index, shifting all values after it to the left.
+    Remove(usize),
+    /// Replaces the value at `index` with `value`.
+    Replace {
+        /// The index of the value to replace.
+        index: usize,
+        /// The value to replace it with.
+        value: T,
+    },
+    /// Shortens the [`Vec`] to the given length, if it's longer.
+    Truncate(usize),
+    /// Replaces the entire [`Vec`].
+    Set(Vec<T>),
+}
+
+impl<T> VecOp<T> {
+    /// Gets the length a [`Vec`] of length `len` would have after applying the operation, or a
+    /// [`VecPatchError`] if the operation is out of bounds.
+    fn check(&self, len: usize) -> Result<usize, VecPatchError> {
+        let out_of_bounds = |index| Err(VecPatchError { index, len });
+
+        match self {
+            Self::Push(_) => Ok(len + 1),
+            Self::Insert { index, .. } if *index > len => out_of_bounds(*index),
+            Self::Insert { .. } => Ok(len + 1),
+            Self::Remove(index) if *index >= len => out_of_bounds(*index),
+            Self::Remove(_) => Ok(len - 1),
+            Self::Replace { index, .. } if *index >= len => out_of_bounds(*index),
+            Self::Replace { .. } => Ok(len),
+            Self::Truncate(new_len) => Ok(len.min(*new_len)),
+            Self::Set(values) => Ok(values.len()),
+        }
+    }
+
+    /// Applies the operation to `vec`, returning `true` when updates were made.
+    ///
+    /// Note: Assumes the operation was already checked with [`VecOp::check`].
+    fn apply(self, vec: &mut Vec<T>) -> bool {
+        match self {
+            Self::Push(value) => vec.push(value),
+            Self::Insert { index, value } => vec.insert(index, value),
+            Self::Remove(index) => {
+                vec.remove(index);
+            }
+            Self::Replace { index, value } => vec[index] = value,
+            Self::Truncate(len) if len >= vec.len() => return false,
+            Self::Truncate(len) => vec.truncate(len),
+            Self::Set(values) => *vec = values,
+        }
+
+        true
+    }
+}
+
+/// A set of [`VecOp`] operations that are applied to a [`Vec`] in order, allowing a [`Vec`] to be
+/// updated without replacing it entirely.
+///
+/// Note: To use [`VecPatch`] for a [`Vec`] field of a derived struct, use the `nested` field option.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct VecPatch<T> {
+    ops: Vec<VecOp<T>>,
+}
+
+impl<T> VecPatch<T> {
+    /// Creates a new [`VecPatch`], without any operations.
+    pub const fn new() -> Self {
+        Self { ops: Vec::new() }
+    }
+
+    /// Adds `op` to the end of the patch.
+    pub fn add(&mut self, op: VecOp<T>) {
+        self.ops.push(op);
+    }
+
+    /// Adds `op` to the end of the patch, returning the patch.
+    pub fn with(mut self, op: VecOp<T>) -> Self {
+        self.add(op);
+        self
+    }
+
+    /// Gets the operations of the patch.
+    pub fn ops(&self) -> &[VecOp<T>] {
+        &self.ops
+    }
+
+    /// Gets whether the patch has no operations.
+    pub fn is_empty(&self) -> bool {
+        self.ops.is_empty()
+    }
+
+    /// Converts the patch into its operations.
+    pub fn into_ops(self) -> Vec<VecOp<T>> {
+        self.ops
+    }
+}
+
+impl<T> Default for VecPatch<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> From<Vec<VecOp<T>>> for VecPatch<T> {
+    fn from(ops: Vec<VecOp<T>>) -> Self {
+        Self { ops }
+    }
+}
+
+impl<T> FromIterator<VecOp<T>> for VecPatch<T> {
+    fn from_iter<I: IntoIterator<Item = VecOp<T>>>(iter: I) -> Self {
+        Self {
+            ops: iter.into_iter().collect(),
+        }
+    }
+}
+
+/// The error returned when a [`VecOp`] refers to an index that is out of bounds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct VecPatchError {
+    /// The out of bounds index.
+    pub index: usize,
+    /// The length of the [`Vec`] when the operation was applied.
+    pub len: usize,
+}
+
+impl fmt::Display for VecPatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "index `{}` is out of bounds for length `{}`",
+            self.index, self.len
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for VecPatchError {}
+
+/// Applies each operation in order.
+///
+/// Note: Operations that are out of bounds are skipped. See the [`TryPartial`] implementation
+/// for rejecting them instead.
+impl<T> Partial for Vec<T> {
+    type Item = VecPatch<T>;
+
+    fn apply_some(&mut self, partial: Self::Item) -> bool {
+        let mut will_apply_some = false;
+
+        for op in partial.ops {
+            if op.check(self.len()).is_ok() {
+                will_apply_some |= op.apply(self);
+            }
+        }
+
+        will_apply_some
+    }
+}
+
+/// Applies each operation in order, or returns a [`VecPatchError`] without applying anything when
+/// any operation is out of bounds.
+impl<T> TryPartial for Vec<T> {
+    type Item = VecPatch<T>;
+    type Error = VecPatchError;
+
+    fn try_apply_some(&mut self, partial: Self::Item) -> Result<bool, Self::Error> {
+        // every operation is checked before anything is applied, so that an error leaves `self` untouched
+        partial
+            .ops
+            .iter()
+            .try_fold(self.len(), |len, op| op.check(len))?;
+
+        let mut will_apply_some = false;
+
+        for op in partial.ops {
+            will_apply_some |= op.apply(self);
+        }
+
+        Ok(will_apply_some)
+    }
+}
@@ -0,0 +1,62 @@
+use core::fmt::Display;
+
+use partially::Partial;
+
+#[derive(Partial, Debug, PartialEq)]
+struct Wrapper<T> {
+    #[partially(nested, bound = "T: Partial")]
+    inner: T,
+    label: String,
+}
+
+#[derive(Partial, Debug, Default, PartialEq)]
+#[partially(derive(Debug, Default, PartialEq))]
+struct Inner {
+    value: u32,
+}
+
+#[test]
+fn field_bound_extends() {
+    let mut wrapper = Wrapper {
+        inner: Inner { value: 1 },
+        label: "initial".to_string(),
+    };
+
+    assert!(wrapper.apply_some(PartialWrapper {
+        inner: Some(PartialInner { value: Some(2) }),
+        label: None,
+    }));
+
+    assert_eq!(
+        wrapper,
+        Wrapper {
+            inner: Inner { value: 2 },
+            label: "initial".to_string(),
+        }
+    );
+}
+
+#[derive(Partial, Debug, PartialEq)]
+#[partially(derive(Debug, PartialEq), bound = "T: Clone")]
+struct Labelled<T>
+where
+    T: Clone + Display,
+{
+    value: T,
+}
+
+#[test]
+fn container_bound_replaces() {
+    // `Vec<u8>` isn't `Display`, but the generated struct only requires `Clone`
+    let mut partial: PartialLabelled<Vec<u8>> = PartialLabelled { value: None };
+
+    assert!(partial.apply_some(PartialLabelled {
+        value: Some(vec![1]),
+    }));
+    assert_eq!(partial.value, Some(vec![1]));
+
+    let mut labelled = Labelled { value: 1 };
+
+    assert!(labelled.apply_some(PartialLabelled { value: Some(2) }));
+    assert_eq!(labelled, Labelled { value: 2 });
+}
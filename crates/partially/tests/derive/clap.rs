@@ -0,0 +1,206 @@
+use clap::Parser;
+use partially::Partial;
+
+#[derive(Partial, Debug, PartialEq)]
+#[partially(derive(Debug, PartialEq), clap)]
+struct Data {
+    name: String,
+    port: u16,
+}
+
+#[derive(Parser)]
+struct DataCli {
+    #[command(flatten)]
+    data: PartialData,
+}
+
+#[test]
+fn clap_parses_flags() {
+    let cli = DataCli::try_parse_from(["app", "--name", "demo", "--port", "8080"]).unwrap();
+
+    assert_eq!(
+        cli.data,
+        PartialData {
+            name: Some("demo".to_string()),
+            port: Some(8080),
+        }
+    );
+}
+
+#[test]
+fn clap_parses_without_flags() {
+    let cli = DataCli::try_parse_from(["app"]).unwrap();
+
+    assert_eq!(
+        cli.data,
+        PartialData {
+            name: None,
+            port: None,
+        }
+    );
+}
+
+#[test]
+fn clap_rejects_invalid_flags() {
+    assert!(DataCli::try_parse_from(["app", "--port", "http"]).is_err());
+    assert!(DataCli::try_parse_from(["app", "--host", "localhost"]).is_err());
+}
+
+#[test]
+fn clap_apply_some() {
+    let mut data = Data {
+        name: "app".to_string(),
+        port: 80,
+    };
+
+    let cli = DataCli::try_parse_from(["app", "--port", "8080"]).unwrap();
+
+    assert!(data.apply_some(cli.data));
+    assert_eq!(
+        data,
+        Data {
+            name: "app".to_string(),
+            port: 8080,
+        }
+    );
+}
+
+#[derive(Partial)]
+#[partially(derive(Debug, PartialEq), clap)]
+#[allow(dead_code)]
+struct Renamed {
+    #[partially(rename = "log_level")]
+    level: u8,
+}
+
+#[derive(Parser)]
+struct RenamedCli {
+    #[command(flatten)]
+    renamed: PartialRenamed,
+}
+
+#[test]
+fn clap_renamed_flags() {
+    let cli = RenamedCli::try_parse_from(["app", "--log-level", "3"]).unwrap();
+
+    // the flag is named after the field of the partial
+    assert_eq!(cli.renamed, PartialRenamed { log_level: Some(3) });
+    assert!(RenamedCli::try_parse_from(["app", "--level", "3"]).is_err());
+}
+
+#[derive(Partial)]
+#[partially(derive(Debug, PartialEq), clap)]
+#[allow(dead_code)]
+struct Outer {
+    name: String,
+    #[partially(nested)]
+    inner: Inner,
+}
+
+#[derive(Partial)]
+#[partially(derive(Debug, PartialEq), clap)]
+#[allow(dead_code)]
+struct Inner {
+    host: String,
+    port: u16,
+}
+
+#[derive(Parser)]
+struct OuterCli {
+    #[command(flatten)]
+    outer: PartialOuter,
+}
+
+#[test]
+fn clap_nested_flags() {
+    let cli = OuterCli::try_parse_from(["app", "--port", "8080"]).unwrap();
+
+    // the flags of the nested partial are flattened into the parent
+    assert_eq!(
+        cli.outer,
+        PartialOuter {
+            name: None,
+            inner: Some(PartialInner {
+                host: None,
+                port: Some(8080),
+            }),
+        }
+    );
+
+    let cli = OuterCli::try_parse_from(["app", "--name", "demo"]).unwrap();
+
+    // the nested partial is `None` when none of its flags are present
+    assert_eq!(
+        cli.outer,
+        PartialOuter {
+            name: Some("demo".to_string()),
+            inner: None,
+        }
+    );
+}
+
+#[derive(Partial, Debug, PartialEq)]
+#[partially(derive(Debug, PartialEq), clap)]
+struct Limits {
+    #[partially(transparent)]
+    timeout: Option<u64>,
+    #[partially(as_type = "Option<u32>")]
+    retries: u32,
+}
+
+#[derive(Parser)]
+struct LimitsCli {
+    #[command(flatten)]
+    limits: PartialLimits,
+}
+
+#[test]
+fn clap_parses_optional_transparent_fields_without_flags() {
+    let cli = LimitsCli::try_parse_from(["app"]).unwrap();
+
+    assert_eq!(
+        cli.limits,
+        PartialLimits {
+            timeout: None,
+            retries: None,
+        }
+    );
+
+    let mut limits = Limits {
+        timeout: Some(30),
+        retries: 3,
+    };
+
+    assert!(!limits.apply_some(cli.limits));
+    assert_eq!(
+        limits,
+        Limits {
+            timeout: Some(30),
+            retries: 3,
+        }
+    );
+}
+
+#[derive(Partial)]
+#[partially(clap)]
+#[allow(dead_code)]
+struct Documented {
+    /// The name of the application.
+    name: String,
+}
+
+#[derive(Parser)]
+struct DocumentedCli {
+    #[command(flatten)]
+    documented: PartialDocumented,
+}
+
+#[test]
+fn clap_help_uses_doc_comments() {
+    let help = <DocumentedCli as clap::CommandFactory>::command()
+        .render_long_help()
+        .to_string();
+
+    assert!(help.contains("--name <NAME>"));
+    assert!(help.contains("The name of the application"));
+}
@@ -0,0 +1,113 @@
+use partially::{Partial, PartialReport, TryPartial};
+
+#[derive(Partial)]
+#[partially(derive(Default), compare)]
+struct Data {
+    a: String,
+    b: String,
+}
+
+#[test]
+fn compare_unchanged() {
+    let mut data = Data {
+        a: "initial".to_string(),
+        b: "initial".to_string(),
+    };
+
+    assert!(!data.apply_some(PartialData {
+        a: Some("initial".to_string()),
+        b: Some("initial".to_string()),
+    }));
+}
+
+#[test]
+fn compare_changed() {
+    let mut data = Data {
+        a: "initial".to_string(),
+        b: "initial".to_string(),
+    };
+
+    assert!(data.apply_some(PartialData {
+        a: Some("initial".to_string()),
+        b: Some("modified".to_string()),
+    }));
+
+    assert_eq!(data.b, "modified");
+}
+
+#[derive(Partial)]
+#[partially(derive(Default), compare)]
+struct Outer {
+    #[partially(nested)]
+    inner: Inner,
+}
+
+#[derive(Partial)]
+#[partially(derive(Default), compare)]
+struct Inner {
+    value: u16,
+}
+
+#[test]
+fn compare_nested() {
+    let mut outer = Outer {
+        inner: Inner { value: 1 },
+    };
+
+    assert!(!outer.apply_some(PartialOuter {
+        inner: Some(PartialInner { value: Some(1) }),
+    }));
+
+    assert!(outer.apply_some(PartialOuter {
+        inner: Some(PartialInner { value: Some(2) }),
+    }));
+
+    assert_eq!(outer.inner.value, 2);
+}
+
+#[derive(Partial)]
+#[partially(derive(Default), compare, report)]
+struct Reported {
+    #[partially(rename = "title")]
+    name: String,
+    count: u8,
+}
+
+#[test]
+fn compare_report() {
+    let mut reported = Reported {
+        name: "initial".to_string(),
+        count: 1,
+    };
+
+    let report = reported.apply_some_report(PartialReported {
+        title: Some("modified".to_string()),
+        count: Some(1),
+    });
+
+    // only the changed field is reported
+    assert_eq!(report, ReportedFields::NAME);
+    assert_eq!(reported.name, "modified");
+}
+
+#[derive(Partial)]
+#[partially(derive(Default), compare, error = "std::num::TryFromIntError")]
+struct Pool {
+    #[partially(try_into, as_type = "Option<i64>")]
+    workers: u8,
+}
+
+#[test]
+fn compare_try_partial() {
+    let mut pool = Pool { workers: 1 };
+
+    assert!(!pool
+        .try_apply_some(PartialPool { workers: Some(1) })
+        .unwrap());
+
+    assert!(pool
+        .try_apply_some(PartialPool { workers: Some(2) })
+        .unwrap());
+
+    assert_eq!(pool.workers, 2);
+}
@@ -0,0 +1,155 @@
+use partially::{Diff, Partial};
+
+#[derive(Partial)]
+#[partially(derive(Debug, PartialEq), diff)]
+struct Data {
+    a: String,
+    b: String,
+}
+
+#[test]
+fn diff_equal() {
+    let old = Data {
+        a: "initial".to_string(),
+        b: "initial".to_string(),
+    };
+    let new = Data {
+        a: "initial".to_string(),
+        b: "initial".to_string(),
+    };
+
+    assert_eq!(old.diff(&new), PartialData { a: None, b: None });
+}
+
+#[test]
+fn diff_changed() {
+    let mut data = Data {
+        a: "initial".to_string(),
+        b: "initial".to_string(),
+    };
+    let new = Data {
+        a: "initial".to_string(),
+        b: "modified".to_string(),
+    };
+
+    let partial = data.diff(&new);
+
+    assert_eq!(
+        partial,
+        PartialData {
+            a: None,
+            b: Some("modified".to_string()),
+        }
+    );
+
+    assert!(data.apply_some(partial));
+    assert_eq!(data.b, "modified");
+}
+
+#[derive(Partial)]
+#[partially(derive(Debug, PartialEq), diff)]
+struct Renamed {
+    #[partially(rename = "title")]
+    name: String,
+    #[partially(omit)]
+    #[allow(dead_code)]
+    revision: u32,
+}
+
+#[test]
+fn diff_renamed() {
+    let old = Renamed {
+        name: "initial".to_string(),
+        revision: 1,
+    };
+    let new = Renamed {
+        name: "modified".to_string(),
+        revision: 2,
+    };
+
+    // omitted fields aren't part of the diff
+    assert_eq!(
+        old.diff(&new),
+        PartialRenamed {
+            title: Some("modified".to_string()),
+        }
+    );
+}
+
+#[derive(Partial)]
+#[partially(derive(Debug, PartialEq), diff)]
+struct Outer {
+    #[partially(nested)]
+    inner: Inner,
+}
+
+#[derive(Partial, PartialEq)]
+#[partially(derive(Debug, PartialEq), diff)]
+struct Inner {
+    a: u16,
+    b: u16,
+}
+
+#[test]
+fn diff_nested() {
+    let old = Outer {
+        inner: Inner { a: 1, b: 1 },
+    };
+    let new = Outer {
+        inner: Inner { a: 1, b: 2 },
+    };
+
+    // only the changed fields of the nested struct are included
+    assert_eq!(
+        old.diff(&new),
+        PartialOuter {
+            inner: Some(PartialInner {
+                a: None,
+                b: Some(2),
+            }),
+        }
+    );
+    assert_eq!(old.diff(&old), PartialOuter { inner: None });
+}
+
+#[derive(Partial, Debug, PartialEq)]
+#[partially(derive(Debug, PartialEq), diff)]
+struct Labels {
+    #[partially(transparent)]
+    label: Option<String>,
+    #[partially(transparent)]
+    tags: Vec<String>,
+}
+
+#[test]
+fn diff_transparent() {
+    let mut labels = Labels {
+        label: Some("old".to_string()),
+        tags: vec!["a".to_string()],
+    };
+    let new = Labels {
+        label: Some("new".to_string()),
+        tags: vec!["b".to_string()],
+    };
+
+    assert_eq!(
+        labels.diff(&labels),
+        PartialLabels {
+            label: None,
+            tags: vec!["a".to_string()],
+        }
+    );
+
+    let partial = labels.diff(&new);
+
+    assert_eq!(
+        partial,
+        PartialLabels {
+            label: Some("new".to_string()),
+            tags: vec!["b".to_string()],
+        }
+    );
+
+    assert!(labels.apply_some(partial));
+    assert_eq!(labels, new);
+}
@@ -0,0 +1,261 @@
+use partially::{Partial, Patch, TryPartial, VariantError};
+
+#[derive(Partial, Debug, Clone, PartialEq)]
+#[partially(derive(Debug, Clone, PartialEq))]
+enum State {
+    Idle,
+    Running {
+        pid: u32,
+        name: String,
+        #[partially(omit)]
+        restarts: u8,
+    },
+    Done(i32, #[partially(transparent)] Option<String>),
+}
+
+#[test]
+fn enum_same_variant_patches_in_place() {
+    let mut state = State::Running {
+        pid: 1,
+        name: "initial".to_string(),
+        restarts: 3,
+    };
+
+    assert!(!state.apply_some(PartialState::Running {
+        pid: None,
+        name: None,
+    }));
+
+    assert!(state.apply_some(PartialState::Running {
+        pid: Some(2),
+        name: None,
+    }));
+
+    assert_eq!(
+        state,
+        State::Running {
+            pid: 2,
+            name: "initial".to_string(),
+            restarts: 3,
+        }
+    );
+}
+
+#[test]
+fn enum_different_variant_replaces_when_complete() {
+    let mut state = State::Idle;
+
+    assert!(state.apply_some(PartialState::Running {
+        pid: Some(1),
+        name: Some("name".to_string()),
+    }));
+
+    assert_eq!(
+        state,
+        State::Running {
+            pid: 1,
+            name: "name".to_string(),
+            restarts: 0,
+        }
+    );
+
+    assert!(state.apply_some(PartialState::Done(Some(0), None)));
+    assert_eq!(state, State::Done(0, None));
+
+    assert!(state.apply_some(PartialState::Idle));
+    assert_eq!(state, State::Idle);
+}
+
+#[test]
+fn enum_different_variant_rejects_when_incomplete() {
+    let mut state = State::Idle;
+
+    assert!(!state.apply_some(PartialState::Running {
+        pid: Some(1),
+        name: None,
+    }));
+    assert_eq!(state, State::Idle);
+
+    assert!(!state.apply_some(PartialState::Done(None, Some("reason".to_string()))));
+    assert_eq!(state, State::Idle);
+}
+
+#[test]
+fn enum_try_apply_some_rejects_when_incomplete() {
+    let mut state = State::Idle;
+
+    assert_eq!(
+        state.try_apply_some(PartialState::Running {
+            pid: Some(1),
+            name: None,
+        }),
+        Err(VariantError { variant: "Running" })
+    );
+    assert_eq!(state, State::Idle);
+
+    // partials of the same variant are never rejected, even when nothing is applied
+    assert_eq!(state.try_apply_some(PartialState::Idle), Ok(false));
+
+    assert_eq!(
+        state.try_apply_some(PartialState::Done(Some(0), None)),
+        Ok(true)
+    );
+    assert_eq!(state, State::Done(0, None));
+}
+
+#[test]
+fn enum_transparent_applies_some() {
+    let mut state = State::Done(1, Some("reason".to_string()));
+
+    assert!(!state.apply_some(PartialState::Done(None, None)));
+    assert_eq!(state, State::Done(1, Some("reason".to_string())));
+
+    assert!(state.apply_some(PartialState::Done(None, Some("other".to_string()))));
+    assert_eq!(state, State::Done(1, Some("other".to_string())));
+}
+
+#[test]
+fn enum_partial_apply_some() {
+    let mut partial = PartialState::Running {
+        pid: Some(1),
+        name: None,
+    };
+
+    assert!(partial.apply_some(PartialState::Running {
+        pid: None,
+        name: Some("name".to_string()),
+    }));
+    assert_eq!(
+        partial,
+        PartialState::Running {
+            pid: Some(1),
+            name: Some("name".to_string()),
+        }
+    );
+
+    assert!(partial.apply_some(PartialState::Done(None, None)));
+    assert_eq!(partial, PartialState::Done(None, None));
+}
+
+#[derive(Partial, Debug, PartialEq)]
+#[partially(derive(Debug, PartialEq), compare, try_from)]
+struct Config {
+    value: u32,
+}
+
+#[derive(Partial, Debug, PartialEq)]
+#[partially(derive(Debug, PartialEq), compare)]
+enum Job {
+    Scheduled {
+        #[partially(patch)]
+        label: Option<String>,
+        #[partially(nested)]
+        config: Config,
+    },
+    Cancelled,
+}
+
+#[test]
+fn enum_nested_and_patch() {
+    let mut job = Job::Scheduled {
+        label: Some("label".to_string()),
+        config: Config { value: 1 },
+    };
+
+    assert!(!job.apply_some(PartialJob::Scheduled {
+        label: Patch::Value("label".to_string()),
+        config: Some(PartialConfig { value: Some(1) }),
+    }));
+
+    assert!(job.apply_some(PartialJob::Scheduled {
+        label: Patch::Null,
+        config: Some(PartialConfig { value: Some(2) }),
+    }));
+
+    assert_eq!(
+        job,
+        Job::Scheduled {
+            label: None,
+            config: Config { value: 2 },
+        }
+    );
+
+    assert!(job.apply_some(PartialJob::Cancelled));
+    assert_eq!(job, Job::Cancelled);
+
+    // nested values are converted using `TryFrom`, so incomplete nested partials are rejected
+    assert!(!job.apply_some(PartialJob::Scheduled {
+        label: Patch::Missing,
+        config: Some(PartialConfig { value: None }),
+    }));
+
+    assert!(job.apply_some(PartialJob::Scheduled {
+        label: Patch::Null,
+        config: Some(PartialConfig { value: Some(3) }),
+    }));
+    assert_eq!(
+        job,
+        Job::Scheduled {
+            label: None,
+            config: Config { value: 3 },
+        }
+    );
+}
+
+#[derive(Partial, Debug, PartialEq)]
+#[partially(derive(Debug, PartialEq))]
+enum Slot<T> {
+    Empty,
+    Filled(T),
+}
+
+#[test]
+fn enum_generic() {
+    let mut slot = Slot::Empty;
+
+    assert!(slot.apply_some(PartialSlot::Filled(Some(1))));
+    assert_eq!(slot, Slot::Filled(1));
+
+    assert!(slot.apply_some(PartialSlot::Filled(Some(2))));
+    assert_eq!(slot, Slot::Filled(2));
+    assert!(slot.apply_some(PartialSlot::Empty));
+    assert_eq!(slot, Slot::Empty);
+}
+
+#[derive(Partial, Debug, PartialEq)]
+#[partially(derive(Debug, PartialEq))]
+struct Limits {
+    max: u32,
+}
+
+// a single variant is only ever patched in place, so nested fields don't need `TryFrom`
+#[derive(Partial, Debug, PartialEq)]
+#[partially(derive(Debug, PartialEq))]
+enum Quota {
+    Limited {
+        #[partially(nested)]
+        limits: Limits,
+    },
+}
+
+#[test]
+fn enum_single_variant_nested() {
+    let mut quota = Quota::Limited {
+        limits: Limits { max: 1 },
+    };
+
+    assert!(quota.apply_some(PartialQuota::Limited {
+        limits: Some(PartialLimits { max: Some(2) }),
+    }));
+    assert_eq!(
+        quota,
+        Quota::Limited {
+            limits: Limits { max: 2 },
+        }
+    );
+
+    assert_eq!(
+        quota.try_apply_some(PartialQuota::Limited { limits: None }),
+        Ok(false)
+    );
+}
@@ -0,0 +1,117 @@
+#![cfg(feature = "alloc")]
+
+use std::collections::HashMap;
+
+use partially::{EnvError, FromEnv, Partial, Patch};
+
+#[derive(Partial)]
+#[partially(derive(Debug, Default, PartialEq))]
+#[partially(env)]
+#[allow(dead_code)] // only the generated struct is used
+struct Config {
+    host: String,
+    #[partially(rename = "port_number")]
+    port: u16,
+    #[partially(patch)]
+    user: Option<String>,
+    #[partially(nested)]
+    database: Database,
+}
+
+#[derive(Partial)]
+#[partially(derive(Debug, Default, PartialEq))]
+#[partially(env)]
+#[allow(dead_code)] // only the generated struct is used
+struct Database {
+    url: String,
+    pool_size: u32,
+}
+
+#[derive(Partial, Debug, PartialEq)]
+#[partially(env)]
+struct Limits {
+    #[partially(transparent)]
+    timeout: Option<u32>,
+}
+
+fn lookup<'a>(vars: &'a [(&'a str, &'a str)]) -> impl FnMut(&str) -> Option<String> + 'a {
+    let vars: HashMap<_, _> = vars.iter().copied().collect();
+
+    move |name| vars.get(name).map(|value| value.to_string())
+}
+
+#[test]
+fn env_reads_variables() {
+    let vars = [
+        ("APP_HOST", "localhost"),
+        ("APP_PORT_NUMBER", "8080"),
+        ("APP_USER", "admin"),
+        ("APP_DATABASE__POOL_SIZE", "4"),
+        ("OTHER_HOST", "example.com"),
+    ];
+
+    let partial = PartialConfig::from_env_with("APP", lookup(&vars)).unwrap();
+
+    assert_eq!(
+        partial,
+        PartialConfig {
+            host: Some("localhost".to_string()),
+            port_number: Some(8080),
+            user: Patch::Value("admin".to_string()),
+            database: Some(PartialDatabase {
+                url: None,
+                pool_size: Some(4),
+            }),
+        }
+    );
+}
+
+#[test]
+fn env_leaves_missing_variables() {
+    let partial = PartialConfig::from_env_with("APP", lookup(&[])).unwrap();
+
+    assert_eq!(partial, PartialConfig::default());
+}
+
+#[test]
+fn env_without_prefix() {
+    let vars = [("HOST", "localhost"), ("DATABASE__URL", "postgres://db")];
+
+    let partial = PartialConfig::from_env_with("", lookup(&vars)).unwrap();
+
+    assert_eq!(partial.host, Some("localhost".to_string()));
+    assert_eq!(
+        partial.database.unwrap().url,
+        Some("postgres://db".to_string())
+    );
+}
+
+#[test]
+fn env_parse_error() {
+    let vars = [("APP_DATABASE__POOL_SIZE", "many")];
+
+    let err = PartialConfig::from_env_with("APP", lookup(&vars)).unwrap_err();
+
+    assert_eq!(
+        err,
+        EnvError {
+            variable: "APP_DATABASE__POOL_SIZE".to_string(),
+            message: "invalid digit found in string".to_string(),
+        }
+    );
+}
+
+#[test]
+fn env_missing_transparent_keeps_value() {
+    let mut limits = Limits { timeout: Some(30) };
+
+    let partial = PartialLimits::from_env_with("APP", lookup(&[])).unwrap();
+
+    assert!(!limits.apply_some(partial));
+    assert_eq!(limits.timeout, Some(30));
+
+    let partial = PartialLimits::from_env_with("APP", lookup(&[("APP_TIMEOUT", "60")])).unwrap();
+
+    assert!(limits.apply_some(partial));
+    assert_eq!(limits.timeout, Some(60));
+}
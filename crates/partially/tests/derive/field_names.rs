@@ -0,0 +1,70 @@
+use partially::{FieldNames, Partial};
+
+#[derive(Partial)]
+#[partially(derive(Default))]
+#[partially(field_names)]
+#[allow(dead_code)] // only the generated struct is used
+struct Data {
+    name: String,
+    #[partially(omit)]
+    id: u64,
+    #[partially(rename = "port_number")]
+    port: u16,
+    #[partially(nested)]
+    inner: Inner,
+}
+
+#[derive(Partial)]
+#[partially(derive(Default))]
+#[partially(field_names)]
+#[allow(dead_code)] // only the generated struct is used
+struct Inner {
+    value: u8,
+}
+
+#[test]
+fn field_names_skips_omit_and_renames() {
+    assert_eq!(PartialData::FIELD_NAMES, &["name", "port_number", "inner"]);
+}
+
+#[test]
+fn field_names_field_is_some() {
+    let partial = PartialData {
+        port_number: Some(8080),
+        ..Default::default()
+    };
+
+    assert!(!partial.field_is_some(0));
+    assert!(partial.field_is_some(1));
+    assert!(!partial.field_is_some(2));
+    assert!(!partial.field_is_some(3));
+}
+
+#[test]
+fn field_names_some_field_names() {
+    let partial = PartialData {
+        port_number: Some(8080),
+        inner: Some(PartialInner::default()),
+        ..Default::default()
+    };
+
+    assert!(partial.some_field_names().eq(["port_number", "inner"]));
+    assert_eq!(PartialData::default().some_field_names().count(), 0);
+}
+
+#[test]
+fn field_names_path_is_some() {
+    let partial = PartialData {
+        port_number: Some(8080),
+        inner: Some(PartialInner { value: Some(1) }),
+        ..Default::default()
+    };
+
+    assert!(partial.path_is_some("port_number"));
+    assert!(partial.path_is_some("inner"));
+    assert!(partial.path_is_some("inner.value"));
+    assert!(!partial.path_is_some("name"));
+    assert!(!partial.path_is_some("inner.unknown"));
+    assert!(!partial.path_is_some("port_number.value"));
+    assert!(!PartialData::default().path_is_some("inner.value"));
+}
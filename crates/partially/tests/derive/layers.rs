@@ -0,0 +1,230 @@
+#![cfg(feature = "alloc")]
+
+use partially::{Layers, Partial};
+
+#[derive(Partial, Debug, Default, PartialEq)]
+#[partially(derive(Default))]
+struct Data {
+    a: u16,
+    b: u16,
+}
+
+#[test]
+fn layers_resolve() {
+    let layers = Layers::<Data>::new()
+        .with(
+            "file",
+            PartialData {
+                a: Some(1),
+                b: Some(1),
+            },
+        )
+        .with(
+            "cli",
+            PartialData {
+                a: None,
+                b: Some(2),
+            },
+        );
+
+    assert_eq!(layers.resolve(Data::default()), Data { a: 1, b: 2 });
+}
+
+#[test]
+fn layers_resolve_without_layers() {
+    assert_eq!(
+        Layers::<Data>::new().resolve(Data { a: 1, b: 1 }),
+        Data { a: 1, b: 1 }
+    );
+}
+
+#[test]
+fn layers_get() {
+    let layers = Layers::<Data>::new()
+        .with("file", PartialData::default())
+        .with(
+            "cli",
+            PartialData {
+                a: Some(1),
+                b: None,
+            },
+        );
+
+    assert_eq!(layers.len(), 2);
+    assert_eq!(layers.get("cli").unwrap().a, Some(1));
+    assert!(layers.get("env").is_none());
+    assert!(layers.iter().map(|(name, _)| name).eq(["file", "cli"]));
+}
+
+#[derive(Partial, Debug, PartialEq)]
+#[partially(derive(Debug, Default, PartialEq), merge, try_from)]
+struct Merged {
+    a: u16,
+    b: u16,
+}
+
+#[test]
+fn layers_merged() {
+    let layers = Layers::<Merged>::new()
+        .with(
+            "file",
+            PartialMerged {
+                a: Some(1),
+                b: Some(1),
+            },
+        )
+        .with(
+            "cli",
+            PartialMerged {
+                a: None,
+                b: Some(2),
+            },
+        );
+
+    assert_eq!(
+        layers.merged(),
+        PartialMerged {
+            a: Some(1),
+            b: Some(2),
+        }
+    );
+}
+
+#[test]
+fn layers_try_resolve() {
+    let layers = Layers::<Merged>::new()
+        .with(
+            "file",
+            PartialMerged {
+                a: Some(1),
+                b: None,
+            },
+        )
+        .with(
+            "cli",
+            PartialMerged {
+                a: None,
+                b: Some(2),
+            },
+        );
+
+    assert_eq!(layers.try_resolve().unwrap(), Merged { a: 1, b: 2 });
+    assert!(Layers::<Merged>::new().try_resolve().is_err());
+}
+
+#[derive(Partial)]
+#[partially(derive(Default), field_names)]
+#[allow(dead_code)]
+struct Named {
+    a: u16,
+    #[partially(rename = "c")]
+    b: u16,
+}
+
+#[test]
+fn layers_origin() {
+    let layers = Layers::<Named>::new()
+        .with(
+            "file",
+            PartialNamed {
+                a: Some(1),
+                c: Some(1),
+            },
+        )
+        .with(
+            "cli",
+            PartialNamed {
+                a: None,
+                c: Some(2),
+            },
+        );
+
+    // origins are looked up by the names of the partial's fields
+    assert_eq!(layers.origin("a"), Some("file"));
+    assert_eq!(layers.origin("c"), Some("cli"));
+    assert_eq!(layers.origin("b"), None);
+
+    assert!(layers
+        .origins()
+        .eq([("a", Some("file")), ("c", Some("cli"))]));
+}
+
+#[derive(Partial, Debug, PartialEq)]
+#[partially(derive(Debug, Default, PartialEq), field_names, merge, try_from)]
+struct Outer {
+    a: u16,
+    #[partially(nested)]
+    inner: Inner,
+}
+
+#[derive(Partial, Debug, PartialEq)]
+#[partially(derive(Debug, Default, PartialEq), field_names, merge, try_from)]
+struct Inner {
+    b: u16,
+    c: u16,
+}
+
+#[test]
+fn layers_merge_nested() {
+    let layers = Layers::<Outer>::new()
+        .with(
+            "file",
+            PartialOuter {
+                a: Some(1),
+                inner: Some(PartialInner {
+                    b: Some(1),
+                    c: None,
+                }),
+            },
+        )
+        .with(
+            "cli",
+            PartialOuter {
+                a: None,
+                inner: Some(PartialInner {
+                    b: None,
+                    c: Some(2),
+                }),
+            },
+        );
+
+    // each layer sets a different field of `inner`, which are merged rather than replaced
+    assert_eq!(
+        layers.try_resolve().unwrap(),
+        Outer {
+            a: 1,
+            inner: Inner { b: 1, c: 2 },
+        }
+    );
+}
+
+#[test]
+fn layers_origin_nested() {
+    let layers = Layers::<Outer>::new()
+        .with(
+            "file",
+            PartialOuter {
+                a: Some(1),
+                inner: Some(PartialInner {
+                    b: Some(1),
+                    c: None,
+                }),
+            },
+        )
+        .with(
+            "cli",
+            PartialOuter {
+                a: None,
+                inner: Some(PartialInner {
+                    b: None,
+                    c: Some(2),
+                }),
+            },
+        );
+
+    assert_eq!(layers.origin("inner"), Some("cli"));
+    assert_eq!(layers.origin("inner.b"), Some("file"));
+    assert_eq!(layers.origin("inner.c"), Some("cli"));
+    assert_eq!(layers.origin("inner.unknown"), None);
+    assert_eq!(layers.origin("a.length"), None);
+}
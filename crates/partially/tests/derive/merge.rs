@@ -0,0 +1,173 @@
+use partially::{Merge, MergeConflict, MergePolicy, Partial};
+
+#[derive(Partial)]
+#[partially(derive(Debug, Default, PartialEq), merge)]
+#[allow(dead_code)]
+struct Data {
+    a: String,
+    b: String,
+}
+
+#[test]
+fn merge_left_wins() {
+    let left = PartialData {
+        a: Some("left".to_string()),
+        b: None,
+    };
+    let right = PartialData {
+        a: Some("right".to_string()),
+        b: Some("right".to_string()),
+    };
+
+    assert_eq!(
+        left.merge(right, MergePolicy::LeftWins).unwrap(),
+        PartialData {
+            a: Some("left".to_string()),
+            b: Some("right".to_string()),
+        }
+    );
+}
+
+#[test]
+fn merge_right_wins() {
+    let left = PartialData {
+        a: Some("left".to_string()),
+        b: Some("left".to_string()),
+    };
+    let right = PartialData {
+        a: Some("right".to_string()),
+        b: None,
+    };
+
+    assert_eq!(
+        left.merge(right, MergePolicy::RightWins).unwrap(),
+        PartialData {
+            a: Some("right".to_string()),
+            b: Some("left".to_string()),
+        }
+    );
+}
+
+#[test]
+fn merge_error_on_conflict() {
+    let left = PartialData {
+        a: Some("left".to_string()),
+        b: None,
+    };
+    let right = PartialData {
+        a: Some("right".to_string()),
+        b: None,
+    };
+
+    assert_eq!(
+        left.merge(right, MergePolicy::ErrorOnConflict),
+        Err(MergeConflict::new("a"))
+    );
+}
+
+#[test]
+fn merge_equal_values() {
+    let left = PartialData {
+        a: Some("same".to_string()),
+        b: None,
+    };
+    let right = PartialData {
+        a: Some("same".to_string()),
+        b: Some("right".to_string()),
+    };
+
+    // equal values are not a conflict
+    assert_eq!(
+        left.merge(right, MergePolicy::ErrorOnConflict).unwrap(),
+        PartialData {
+            a: Some("same".to_string()),
+            b: Some("right".to_string()),
+        }
+    );
+}
+
+#[test]
+fn merge_empty() {
+    let right = PartialData {
+        a: Some("right".to_string()),
+        b: None,
+    };
+
+    assert_eq!(
+        PartialData::default()
+            .merge(right, MergePolicy::ErrorOnConflict)
+            .unwrap(),
+        PartialData {
+            a: Some("right".to_string()),
+            b: None,
+        }
+    );
+}
+
+#[derive(Partial)]
+#[partially(derive(Debug, Default, PartialEq), merge)]
+#[allow(dead_code)]
+struct Outer {
+    #[partially(nested)]
+    inner: Inner,
+}
+
+#[derive(Partial)]
+#[partially(derive(Debug, Default, PartialEq), merge)]
+#[allow(dead_code)]
+struct Inner {
+    a: u16,
+    b: u16,
+}
+
+#[test]
+fn merge_nested() {
+    let left = PartialOuter {
+        inner: Some(PartialInner {
+            a: Some(1),
+            b: None,
+        }),
+    };
+    let right = PartialOuter {
+        inner: Some(PartialInner {
+            a: None,
+            b: Some(2),
+        }),
+    };
+
+    // nested partials are merged field by field
+    assert_eq!(
+        left.merge(right, MergePolicy::ErrorOnConflict).unwrap(),
+        PartialOuter {
+            inner: Some(PartialInner {
+                a: Some(1),
+                b: Some(2),
+            }),
+        }
+    );
+}
+
+#[test]
+fn merge_nested_conflict_has_path() {
+    let left = PartialOuter {
+        inner: Some(PartialInner {
+            a: Some(1),
+            b: None,
+        }),
+    };
+    let right = PartialOuter {
+        inner: Some(PartialInner {
+            a: Some(2),
+            b: None,
+        }),
+    };
+
+    let conflict = left.merge(right, MergePolicy::ErrorOnConflict).unwrap_err();
+
+    assert_eq!(conflict.field(), "a");
+    assert_eq!(conflict.path(), "inner.a");
+    assert_eq!(
+        conflict.to_string(),
+        "conflicting values for field `inner.a`"
+    );
+}
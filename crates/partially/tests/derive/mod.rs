@@ -1,4 +1,24 @@
-mod basic;
-mod container_attrs;
-mod generic;
-mod retyped;
+mod basic;
+mod bound;
+mod clap;
+mod compare;
+mod container_attrs;
+mod diff;
+mod enums;
+mod env;
+mod field_names;
+mod generic;
+mod layers;
+mod merge;
+mod nested;
+mod patch;
+mod phantom;
+mod report;
+mod retyped;
+mod serde_sparse;
+mod set_field;
+mod transparent;
+mod try_from;
+mod try_partial;
+mod tuple;
+mod undo;
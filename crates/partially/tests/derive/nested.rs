@@ -0,0 +1,69 @@
+use partially::Partial;
+
+#[derive(Partial)]
+#[partially(derive(Default))]
+struct Outer {
+    name: String,
+    #[partially(nested)]
+    inner: Inner,
+}
+
+#[derive(Partial)]
+#[partially(derive(Default))]
+struct Inner {
+    a: u16,
+    b: u16,
+}
+
+#[test]
+fn nested_apply_some() {
+    let mut outer = Outer {
+        name: "initial".to_string(),
+        inner: Inner { a: 1, b: 1 },
+    };
+
+    assert!(outer.apply_some(PartialOuter {
+        name: None,
+        inner: Some(PartialInner {
+            a: None,
+            b: Some(2),
+        }),
+    }));
+
+    assert_eq!(outer.name, "initial");
+    assert_eq!(outer.inner.a, 1);
+    assert_eq!(outer.inner.b, 2);
+}
+
+#[test]
+fn nested_apply_some_empty() {
+    let mut outer = Outer {
+        name: "initial".to_string(),
+        inner: Inner { a: 1, b: 1 },
+    };
+
+    // a present, but empty, nested partial doesn't apply anything
+    assert!(!outer.apply_some(PartialOuter {
+        name: None,
+        inner: Some(PartialInner::default()),
+    }));
+    assert!(!outer.apply_some(PartialOuter::default()));
+
+    assert_eq!(outer.inner.a, 1);
+    assert_eq!(outer.inner.b, 1);
+}
+
+#[test]
+fn nested_partial_apply_some() {
+    let mut partial = PartialOuter::default();
+
+    assert!(partial.apply_some(PartialOuter {
+        name: None,
+        inner: Some(PartialInner {
+            a: None,
+            b: Some(2),
+        }),
+    }));
+
+    assert_eq!(partial.inner.unwrap().b, Some(2));
+}
@@ -0,0 +1,157 @@
+use partially::{Diff, Merge, MergePolicy, Partial, Patch};
+
+#[derive(Partial, Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    partially(derive(Debug, Default, PartialEq, serde::Serialize, serde::Deserialize))
+)]
+#[cfg_attr(not(feature = "serde"), partially(derive(Debug, Default, PartialEq)))]
+#[partially(diff, merge, try_from)]
+struct Data {
+    name: String,
+    #[partially(patch)]
+    nickname: Option<String>,
+}
+
+fn make_data() -> Data {
+    Data {
+        name: "initial".to_string(),
+        nickname: Some("nick".to_string()),
+    }
+}
+
+#[test]
+fn patch_missing() {
+    let mut data = make_data();
+
+    assert!(!data.apply_some(PartialData {
+        name: None,
+        nickname: Patch::Missing,
+    }));
+
+    assert_eq!(data, make_data());
+}
+
+#[test]
+fn patch_null() {
+    let mut data = make_data();
+
+    assert!(data.apply_some(PartialData {
+        name: None,
+        nickname: Patch::Null,
+    }));
+
+    assert_eq!(data.nickname, None);
+}
+
+#[test]
+fn patch_value() {
+    let mut data = make_data();
+
+    assert!(data.apply_some(PartialData {
+        name: None,
+        nickname: Patch::Value("other".to_string()),
+    }));
+
+    assert_eq!(data.nickname, Some("other".to_string()));
+}
+
+#[test]
+fn patch_partial_apply() {
+    let mut partial = PartialData {
+        name: None,
+        nickname: Patch::Value("other".to_string()),
+    };
+
+    assert!(!partial.apply_some(PartialData::default()));
+    assert_eq!(partial.nickname, Patch::Value("other".to_string()));
+
+    assert!(partial.apply_some(PartialData {
+        name: None,
+        nickname: Patch::Null,
+    }));
+    assert_eq!(partial.nickname, Patch::Null);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn patch_serde() {
+    let missing: PartialData = serde_json::from_str(r#"{ "name": "updated" }"#).unwrap();
+    let null: PartialData = serde_json::from_str(r#"{ "name": null, "nickname": null }"#).unwrap();
+    let value: PartialData =
+        serde_json::from_str(r#"{ "name": null, "nickname": "other" }"#).unwrap();
+
+    assert_eq!(missing.nickname, Patch::Missing);
+    assert_eq!(null.nickname, Patch::Null);
+    assert_eq!(value.nickname, Patch::Value("other".to_string()));
+
+    assert_eq!(
+        serde_json::to_string(&missing).unwrap(),
+        r#"{"name":"updated"}"#
+    );
+    assert_eq!(
+        serde_json::to_string(&null).unwrap(),
+        r#"{"name":null,"nickname":null}"#
+    );
+    assert_eq!(
+        serde_json::to_string(&value).unwrap(),
+        r#"{"name":null,"nickname":"other"}"#
+    );
+}
+
+#[test]
+fn patch_diff() {
+    let old = make_data();
+    let new = Data {
+        nickname: None,
+        ..make_data()
+    };
+
+    assert_eq!(old.diff(&old), PartialData::default());
+    assert_eq!(
+        old.diff(&new),
+        PartialData {
+            name: None,
+            nickname: Patch::Null,
+        }
+    );
+}
+
+#[test]
+fn patch_merge() {
+    let left = PartialData {
+        name: None,
+        nickname: Patch::Null,
+    };
+    let right = PartialData {
+        name: Some("right".to_string()),
+        nickname: Patch::Value("right".to_string()),
+    };
+
+    assert_eq!(
+        left.merge(right, MergePolicy::LeftWins).unwrap(),
+        PartialData {
+            name: Some("right".to_string()),
+            nickname: Patch::Null,
+        }
+    );
+}
+
+#[test]
+fn patch_try_from() {
+    let data = Data::try_from(PartialData {
+        name: Some("name".to_string()),
+        nickname: Patch::Null,
+    })
+    .unwrap();
+
+    assert_eq!(data.nickname, None);
+
+    let missing = Data::try_from(PartialData {
+        name: Some("name".to_string()),
+        nickname: Patch::Missing,
+    })
+    .unwrap_err();
+
+    assert!(missing.missing().unwrap().iter().eq(["nickname"]));
+}
@@ -0,0 +1,145 @@
+use partially::{Diff, FieldNames, Merge, MergePolicy, Partial, PartialUndo};
+use serde::{Deserialize, Serialize};
+
+#[derive(Partial, Debug, Clone, PartialEq)]
+#[partially(derive(Debug, Default, PartialEq))]
+#[partially(diff, merge, undo, try_from, field_names)]
+struct Data<T> {
+    id: u64,
+    #[partially(omit, bound = "T: Default")]
+    payload: T,
+}
+
+#[test]
+fn phantom_struct() {
+    let mut data = Data {
+        id: 1,
+        payload: "payload".to_string(),
+    };
+
+    assert_eq!(PartialData::<String>::FIELD_NAMES, ["id"]);
+
+    let partial = PartialData {
+        id: Some(2),
+        ..Default::default()
+    };
+
+    let undo = data.apply_some_with_undo(partial);
+    assert_eq!(data.id, 2);
+    assert_eq!(undo.id, Some(1));
+
+    let diff = data.diff(&Data {
+        id: 3,
+        payload: "other".to_string(),
+    });
+    assert_eq!(diff.id, Some(3));
+
+    let merged = undo.merge(diff, MergePolicy::RightWins).unwrap();
+    assert_eq!(merged.id, Some(3));
+
+    assert_eq!(
+        Data::<String>::try_from(merged),
+        Ok(Data {
+            id: 3,
+            payload: String::new(),
+        })
+    );
+}
+
+#[derive(Partial)]
+#[partially(derive(Default))]
+struct Borrowed<'a> {
+    id: u64,
+    #[partially(omit)]
+    #[allow(dead_code)] // only used by the base struct
+    name: &'a str,
+}
+
+#[test]
+fn phantom_lifetime() {
+    let mut borrowed = Borrowed {
+        id: 1,
+        name: "name",
+    };
+
+    assert!(borrowed.apply_some(PartialBorrowed {
+        id: Some(2),
+        ..Default::default()
+    }));
+    assert_eq!(borrowed.id, 2);
+}
+
+#[derive(Partial, Debug, PartialEq)]
+#[partially(derive(Default))]
+struct Pair<T>(u32, #[partially(omit)] T);
+
+#[test]
+fn phantom_tuple() {
+    let mut pair = Pair(1, 'a');
+
+    assert!(pair.apply_some(PartialPair(Some(2), Default::default())));
+    assert_eq!(pair, Pair(2, 'a'));
+}
+
+#[derive(Partial, Debug, PartialEq)]
+#[partially(derive(Debug, PartialEq))]
+enum Job<T> {
+    Idle,
+    Running {
+        id: u32,
+        #[partially(omit, default = "None")]
+        payload: Option<T>,
+    },
+}
+
+#[test]
+fn phantom_enum() {
+    let mut job = Job::Running {
+        id: 1,
+        payload: Some(1.5),
+    };
+
+    assert!(job.apply_some(PartialJob::Running { id: Some(2) }));
+    assert_eq!(
+        job,
+        Job::Running {
+            id: 2,
+            payload: Some(1.5),
+        }
+    );
+
+    assert!(job.apply_some(PartialJob::Idle));
+    assert!(job.apply_some(PartialJob::Running { id: Some(3) }));
+    assert_eq!(
+        job,
+        Job::Running {
+            id: 3,
+            payload: None
+        }
+    );
+}
+
+#[derive(Partial)]
+#[partially(derive(Debug, Default, PartialEq, Serialize, Deserialize))]
+#[allow(dead_code)] // only the generated struct is used
+struct Serialized<T> {
+    id: u64,
+    #[partially(omit)]
+    payload: T,
+}
+
+#[test]
+fn phantom_serde_skips_marker() {
+    let partial: PartialSerialized<Vec<u8>> = PartialSerialized {
+        id: Some(1),
+        ..Default::default()
+    };
+
+    let json = serde_json::to_string(&partial).unwrap();
+    assert_eq!(json, r#"{"id":1}"#);
+
+    assert_eq!(
+        serde_json::from_str::<PartialSerialized<Vec<u8>>>(&json).unwrap(),
+        partial
+    );
+}
@@ -0,0 +1,147 @@
+use partially::{Partial, PartialReport};
+
+#[derive(Partial)]
+#[partially(derive(Default), report)]
+struct Data {
+    a: String,
+    b: String,
+}
+
+#[test]
+fn report_empty() {
+    let mut data = Data {
+        a: "initial".to_string(),
+        b: "initial".to_string(),
+    };
+
+    let report = data.apply_some_report(PartialData::default());
+
+    assert!(report.is_empty());
+    assert_eq!(report, DataFields::empty());
+    assert_eq!(data.a, "initial");
+}
+
+#[test]
+fn report_fields() {
+    let mut data = Data {
+        a: "initial".to_string(),
+        b: "initial".to_string(),
+    };
+
+    let report = data.apply_some_report(PartialData {
+        a: Some("modified".to_string()),
+        b: None,
+    });
+
+    assert_eq!(report, DataFields::A);
+    assert_eq!(data.a, "modified");
+    assert_eq!(data.b, "initial");
+}
+
+#[test]
+fn report_set() {
+    let mut fields = DataFields::A;
+
+    assert!(fields.contains(DataFields::A));
+    assert!(!fields.contains(DataFields::A | DataFields::B));
+    assert!(fields.intersects(DataFields::A | DataFields::B));
+
+    fields |= DataFields::B;
+
+    assert_eq!(fields, DataFields::all());
+    assert_eq!(fields.bits(), 0b11);
+}
+
+#[derive(Partial)]
+#[partially(derive(Default), report)]
+struct Renamed {
+    #[partially(rename = "title")]
+    name: String,
+    #[partially(omit)]
+    revision: u32,
+    r#type: String,
+}
+
+#[test]
+fn report_renamed_fields() {
+    let mut renamed = Renamed {
+        name: "initial".to_string(),
+        revision: 1,
+        r#type: "basic".to_string(),
+    };
+
+    let report = renamed.apply_some_report(PartialRenamed {
+        title: Some("modified".to_string()),
+        r#type: Some("advanced".to_string()),
+    });
+
+    // the set is named after the fields of the base struct, and skips `omit`-ted fields
+    assert_eq!(report, RenamedFields::NAME | RenamedFields::TYPE);
+    assert_eq!(RenamedFields::all().bits(), 0b11);
+    assert_eq!(renamed.name, "modified");
+    assert_eq!(renamed.revision, 1);
+}
+
+#[derive(Partial)]
+#[partially(derive(Default), report)]
+struct Outer {
+    #[partially(nested)]
+    inner: Inner,
+}
+
+#[derive(Partial)]
+#[partially(derive(Default), report)]
+struct Inner {
+    value: u16,
+}
+
+#[test]
+fn report_nested() {
+    let mut outer = Outer {
+        inner: Inner { value: 1 },
+    };
+
+    let report = outer.apply_some_report(PartialOuter {
+        inner: Some(PartialInner { value: Some(2) }),
+    });
+
+    assert_eq!(report, OuterFields::INNER);
+    assert_eq!(outer.inner.value, 2);
+
+    // an empty nested partial applies nothing, so it's not reported
+    let report = outer.apply_some_report(PartialOuter {
+        inner: Some(PartialInner::default()),
+    });
+
+    assert!(report.is_empty());
+}
+
+#[derive(Partial)]
+#[partially(derive(Default), report, error = "String")]
+struct Pool {
+    #[partially(validate = "validate_workers")]
+    workers: u8,
+}
+
+fn validate_workers(value: &u8) -> Result<(), String> {
+    if *value > 0 {
+        Ok(())
+    } else {
+        Err("workers must be positive".to_string())
+    }
+}
+
+#[test]
+fn report_skips_invalid() {
+    let mut pool = Pool { workers: 1 };
+
+    let report = pool.apply_some_report(PartialPool { workers: Some(0) });
+
+    assert!(report.is_empty());
+    assert_eq!(pool.workers, 1);
+
+    let report = pool.apply_some_report(PartialPool { workers: Some(4) });
+
+    assert_eq!(report, PoolFields::WORKERS);
+    assert_eq!(pool.workers, 4);
+}
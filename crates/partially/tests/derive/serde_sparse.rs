@@ -0,0 +1,81 @@
+use partially::Partial;
+use serde::{Deserialize, Serialize};
+use serde_json::{from_str, to_string};
+
+#[derive(Partial)]
+#[partially(derive(Debug, Default, PartialEq, Serialize, Deserialize))]
+#[partially(serde_sparse)]
+#[allow(dead_code)] // only the generated struct is used
+struct Data {
+    name: String,
+    #[partially(as_type = "Option<i32>")]
+    count: i64,
+    #[partially(transparent)]
+    note: Option<String>,
+}
+
+#[test]
+fn serde_sparse_skips_none() {
+    let partial = PartialData {
+        name: Some("name".to_string()),
+        count: None,
+        note: None,
+    };
+
+    assert_eq!(to_string(&partial).unwrap(), r#"{"name":"name"}"#);
+}
+
+#[test]
+fn serde_sparse_defaults_missing() {
+    let partial: PartialData = from_str(r#"{"count":1}"#).unwrap();
+
+    assert_eq!(
+        partial,
+        PartialData {
+            name: None,
+            count: Some(1),
+            note: None,
+        }
+    );
+}
+
+#[cfg(feature = "serde")]
+mod patch {
+    use partially::{Partial, Patch};
+    use serde::{Deserialize, Serialize};
+    use serde_json::{from_str, to_string};
+
+    #[derive(Partial)]
+    #[partially(derive(Debug, Default, PartialEq, Serialize, Deserialize))]
+    #[partially(serde_sparse)]
+    #[allow(dead_code)] // only the generated struct is used
+    struct Data {
+        #[partially(patch)]
+        nickname: Option<String>,
+        #[partially(patch)]
+        email: Option<String>,
+    }
+
+    #[test]
+    fn serde_sparse_skips_missing() {
+        let partial = PartialData {
+            nickname: Patch::Null,
+            email: Patch::Missing,
+        };
+
+        assert_eq!(to_string(&partial).unwrap(), r#"{"nickname":null}"#);
+    }
+
+    #[test]
+    fn serde_sparse_defaults_missing() {
+        let partial: PartialData = from_str(r#"{"nickname":null}"#).unwrap();
+
+        assert_eq!(
+            partial,
+            PartialData {
+                nickname: Patch::Null,
+                email: Patch::Missing,
+            }
+        );
+    }
+}
@@ -0,0 +1,95 @@
+#![cfg(feature = "alloc")]
+
+use partially::{FieldError, Partial, Patch, SetField};
+
+#[derive(Partial, Debug, PartialEq)]
+#[partially(derive(Debug, Default, PartialEq))]
+#[partially(set_field)]
+struct Data {
+    name: String,
+    #[partially(rename = "port_number")]
+    port: u16,
+    #[partially(patch)]
+    nickname: Option<String>,
+}
+
+#[test]
+fn set_field_sets_values() {
+    let mut partial = PartialData::default();
+
+    partial
+        .set_field("name", Box::new("name".to_string()))
+        .unwrap();
+    partial.set_field("port_number", Box::new(8080u16)).unwrap();
+    partial
+        .set_field("nickname", Box::new(None::<String>))
+        .unwrap();
+
+    assert_eq!(
+        partial,
+        PartialData {
+            name: Some("name".to_string()),
+            port_number: Some(8080),
+            nickname: Patch::Null,
+        }
+    );
+
+    let mut data = Data {
+        name: "initial".to_string(),
+        port: 80,
+        nickname: Some("nick".to_string()),
+    };
+
+    assert!(data.apply_some(partial));
+    assert_eq!(
+        data,
+        Data {
+            name: "name".to_string(),
+            port: 8080,
+            nickname: None,
+        }
+    );
+}
+
+#[test]
+fn set_field_accepts_field_type() {
+    let mut partial = PartialData {
+        port_number: Some(8080),
+        ..Default::default()
+    };
+
+    partial
+        .set_field("port_number", Box::new(None::<u16>))
+        .unwrap();
+    partial
+        .set_field("nickname", Box::new(Patch::Value("nick".to_string())))
+        .unwrap();
+
+    assert_eq!(partial.port_number, None);
+    assert_eq!(partial.nickname, Patch::Value("nick".to_string()));
+}
+
+#[test]
+fn set_field_unknown_field() {
+    let mut partial = PartialData::default();
+
+    assert_eq!(
+        partial.set_field("port", Box::new(8080u16)),
+        Err(FieldError::UnknownField("port".to_string()))
+    );
+    assert_eq!(partial, PartialData::default());
+}
+
+#[test]
+fn set_field_type_mismatch() {
+    let mut partial = PartialData::default();
+
+    assert_eq!(
+        partial.set_field("port_number", Box::new(8080u32)),
+        Err(FieldError::TypeMismatch {
+            field: "port_number",
+            expected: "u16",
+        })
+    );
+    assert_eq!(partial, PartialData::default());
+}
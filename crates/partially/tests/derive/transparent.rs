@@ -0,0 +1,125 @@
+use partially::{FieldNames, Merge, MergeConflict, MergePolicy, Partial, PartialReport};
+
+#[derive(Partial, Debug, Default, PartialEq)]
+#[partially(derive(Debug, Default, PartialEq))]
+#[partially(merge, report, field_names)]
+struct Data {
+    name: String,
+    #[partially(transparent)]
+    tags: Vec<String>,
+    #[partially(transparent = "never")]
+    id: u64,
+}
+
+#[derive(Partial, Debug, Default, PartialEq)]
+#[partially(derive(Debug, Default, PartialEq))]
+struct Labels {
+    #[partially(transparent)]
+    label: Option<String>,
+    #[partially(transparent = "always")]
+    note: Option<String>,
+}
+
+#[test]
+fn transparent_always_overwrites() {
+    let mut data = Data {
+        name: "initial".to_string(),
+        tags: vec!["a".to_string()],
+        id: 1,
+    };
+
+    assert!(data.apply_some(PartialData {
+        name: None,
+        tags: Vec::new(),
+        id: 2,
+    }));
+
+    assert_eq!(
+        data,
+        Data {
+            name: "initial".to_string(),
+            tags: Vec::new(),
+            id: 1,
+        }
+    );
+}
+
+#[test]
+fn transparent_report() {
+    let mut data = Data::default();
+
+    let report = data.apply_some_report(PartialData {
+        name: Some("name".to_string()),
+        tags: vec!["a".to_string()],
+        id: 2,
+    });
+
+    assert_eq!(report, DataFields::NAME | DataFields::TAGS);
+    assert!(!report.contains(DataFields::ID));
+}
+
+#[test]
+fn transparent_field_names() {
+    let partial = PartialData::default();
+
+    assert!(partial.some_field_names().eq(["tags"]));
+}
+
+#[test]
+fn transparent_merge() {
+    let left = PartialData {
+        name: Some("left".to_string()),
+        tags: vec!["left".to_string()],
+        id: 1,
+    };
+    let right = PartialData {
+        name: None,
+        tags: vec!["right".to_string()],
+        id: 2,
+    };
+
+    assert_eq!(
+        left.merge(right, MergePolicy::RightWins).unwrap(),
+        PartialData {
+            name: Some("left".to_string()),
+            tags: vec!["right".to_string()],
+            id: 1,
+        }
+    );
+
+    let left = PartialData {
+        tags: vec!["left".to_string()],
+        ..Default::default()
+    };
+
+    assert_eq!(
+        left.merge(PartialData::default(), MergePolicy::ErrorOnConflict),
+        Err(MergeConflict::new("tags"))
+    );
+}
+
+#[test]
+fn transparent_option_applies_some() {
+    let mut labels = Labels {
+        label: Some("label".to_string()),
+        note: Some("note".to_string()),
+    };
+
+    // `None` doesn't apply to a bare transparent `Option`, while "always" overwrites it
+    assert!(labels.apply_some(PartialLabels::default()));
+
+    assert_eq!(
+        labels,
+        Labels {
+            label: Some("label".to_string()),
+            note: None,
+        }
+    );
+
+    assert!(labels.apply_some(PartialLabels {
+        label: Some("modified".to_string()),
+        note: None,
+    }));
+
+    assert_eq!(labels.label, Some("modified".to_string()));
+}
@@ -0,0 +1,204 @@
+use std::fmt;
+
+use partially::{InvalidField, MissingFields, Partial, TryFromPartialError};
+
+#[derive(Partial, Debug, PartialEq)]
+#[partially(derive(Default), try_from)]
+struct Data {
+    a: String,
+    b: String,
+}
+
+#[test]
+fn try_from_complete() {
+    let partial = PartialData {
+        a: Some("a".to_string()),
+        b: Some("b".to_string()),
+    };
+
+    assert_eq!(
+        Data::try_from(partial),
+        Ok(Data {
+            a: "a".to_string(),
+            b: "b".to_string(),
+        })
+    );
+}
+
+#[test]
+fn try_from_missing() {
+    let err = Data::try_from(PartialData::default()).unwrap_err();
+
+    assert!(err.missing().unwrap().iter().eq(["a", "b"]));
+    assert_eq!(err.to_string(), "missing fields: `a`, `b`");
+}
+
+#[test]
+fn try_from_missing_only_none() {
+    let partial = PartialData {
+        a: None,
+        b: Some("b".to_string()),
+    };
+
+    let mut expected = MissingFields::new(&["a", "b"]);
+    expected.push("a");
+
+    assert_eq!(
+        Data::try_from(partial),
+        Err(TryFromPartialError::Missing(expected))
+    );
+}
+
+fn default_port() -> u16 {
+    8080
+}
+
+#[derive(Partial, Debug, PartialEq)]
+#[partially(derive(Default), try_from)]
+struct Renamed {
+    #[partially(rename = "title")]
+    name: String,
+    #[partially(omit)]
+    revision: u32,
+    #[partially(omit, default = "default_port()")]
+    port: u16,
+}
+
+#[test]
+fn try_from_renamed() {
+    assert_eq!(
+        Renamed::try_from(PartialRenamed {
+            title: Some("name".to_string()),
+        }),
+        Ok(Renamed {
+            name: "name".to_string(),
+            revision: 0,
+            port: 8080,
+        })
+    );
+
+    // renamed fields are reported with the generated name
+    let err = Renamed::try_from(PartialRenamed::default()).unwrap_err();
+
+    assert!(err.missing().unwrap().iter().eq(["title"]));
+}
+
+#[derive(Partial, Debug, PartialEq)]
+#[partially(derive(Default), try_from)]
+struct Outer {
+    #[partially(nested)]
+    inner: Inner,
+}
+
+#[derive(Partial, Debug, PartialEq)]
+#[partially(derive(Default), try_from)]
+struct Inner {
+    value: u16,
+}
+
+#[test]
+fn try_from_nested() {
+    assert_eq!(
+        Outer::try_from(PartialOuter {
+            inner: Some(PartialInner { value: Some(1) }),
+        }),
+        Ok(Outer {
+            inner: Inner { value: 1 },
+        })
+    );
+
+    // the nested field is present, so it's reported as invalid rather than missing
+    let err = Outer::try_from(PartialOuter {
+        inner: Some(PartialInner::default()),
+    })
+    .unwrap_err();
+
+    assert_eq!(err.invalid().unwrap().field(), "inner");
+    assert_eq!(
+        err.to_string(),
+        "invalid field `inner`: missing fields: `value`"
+    );
+}
+
+#[derive(Debug, PartialEq)]
+enum LimitError {
+    OutOfRange,
+    Zero,
+}
+
+impl fmt::Display for LimitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OutOfRange => write!(f, "out of range"),
+            Self::Zero => write!(f, "timeout must not be zero"),
+        }
+    }
+}
+
+impl From<core::num::TryFromIntError> for LimitError {
+    fn from(_: core::num::TryFromIntError) -> Self {
+        Self::OutOfRange
+    }
+}
+
+#[derive(Partial, Debug, PartialEq)]
+#[partially(derive(Default), try_from, error = "LimitError")]
+struct Pool {
+    #[partially(as_type = "Option<i64>", try_into)]
+    workers: u8,
+}
+
+#[test]
+fn try_from_invalid_conversion() {
+    assert_eq!(
+        Pool::try_from(PartialPool { workers: Some(8) }),
+        Ok(Pool { workers: 8 })
+    );
+
+    // the underlying error is kept, next to the name of the field
+    assert_eq!(
+        Pool::try_from(PartialPool {
+            workers: Some(1000)
+        }),
+        Err(TryFromPartialError::Invalid(InvalidField::new(
+            "workers",
+            "out of range integral type conversion attempted"
+        )))
+    );
+}
+
+fn validate_timeout(timeout: &u32) -> Result<(), LimitError> {
+    if *timeout == 0 {
+        Err(LimitError::Zero)
+    } else {
+        Ok(())
+    }
+}
+
+#[derive(Partial, Debug, PartialEq)]
+#[partially(derive(Default), try_from, error = "LimitError")]
+struct Timeout {
+    #[partially(validate = "validate_timeout")]
+    seconds: u32,
+}
+
+#[test]
+fn try_from_invalid_value() {
+    assert_eq!(
+        Timeout::try_from(PartialTimeout { seconds: Some(0) })
+            .unwrap_err()
+            .to_string(),
+        "invalid field `seconds`: timeout must not be zero"
+    );
+
+    // a missing field is still reported as missing
+    let mut expected = MissingFields::new(&["seconds"]);
+    expected.push("seconds");
+
+    assert_eq!(
+        Timeout::try_from(PartialTimeout { seconds: None })
+            .unwrap_err()
+            .missing(),
+        Some(&expected)
+    );
+}
@@ -0,0 +1,192 @@
+use partially::{Partial, TryPartial};
+
+#[derive(Debug, PartialEq)]
+enum ConfigError {
+    OutOfRange,
+    ReservedPort(u16),
+}
+
+impl From<core::num::TryFromIntError> for ConfigError {
+    fn from(_: core::num::TryFromIntError) -> Self {
+        Self::OutOfRange
+    }
+}
+
+fn validate_port(port: &u16) -> Result<(), ConfigError> {
+    if *port < 1024 {
+        Err(ConfigError::ReservedPort(*port))
+    } else {
+        Ok(())
+    }
+}
+
+#[derive(Partial)]
+#[partially(derive(Default), error = "ConfigError")]
+struct Pool {
+    name: String,
+    #[partially(as_type = "Option<i64>", try_into)]
+    workers: u8,
+}
+
+#[test]
+fn try_apply_some() {
+    let mut pool = Pool {
+        name: "initial".to_string(),
+        workers: 4,
+    };
+
+    assert_eq!(pool.try_apply_some(PartialPool::default()), Ok(false));
+
+    assert_eq!(
+        pool.try_apply_some(PartialPool {
+            name: Some("modified".to_string()),
+            workers: Some(8),
+        }),
+        Ok(true)
+    );
+
+    assert_eq!(pool.name, "modified");
+    assert_eq!(pool.workers, 8);
+}
+
+#[test]
+fn try_apply_some_conversion_error() {
+    let mut pool = Pool {
+        name: "initial".to_string(),
+        workers: 4,
+    };
+
+    assert_eq!(
+        pool.try_apply_some(PartialPool {
+            name: Some("modified".to_string()),
+            workers: Some(1000),
+        }),
+        Err(ConfigError::OutOfRange)
+    );
+
+    // nothing is applied when an error occurs
+    assert_eq!(pool.name, "initial");
+    assert_eq!(pool.workers, 4);
+}
+
+#[derive(Partial)]
+#[partially(derive(Default), error = "ConfigError")]
+struct Listener {
+    host: String,
+    #[partially(validate = "validate_port")]
+    port: u16,
+}
+
+#[test]
+fn try_apply_some_validation_error() {
+    let mut listener = Listener {
+        host: "localhost".to_string(),
+        port: 8080,
+    };
+
+    assert_eq!(
+        listener.try_apply_some(PartialListener {
+            host: Some("example.com".to_string()),
+            port: Some(80),
+        }),
+        Err(ConfigError::ReservedPort(80))
+    );
+
+    // nothing is applied when an error occurs
+    assert_eq!(listener.host, "localhost");
+    assert_eq!(listener.port, 8080);
+}
+
+#[derive(Partial)]
+#[partially(derive(Default), error = "ConfigError")]
+struct Limits {
+    #[partially(as_type = "Option<i64>", try_into)]
+    workers: u8,
+    #[partially(validate = "validate_port")]
+    port: u16,
+}
+
+#[test]
+fn apply_some_skips_invalid() {
+    let mut limits = Limits {
+        workers: 4,
+        port: 8080,
+    };
+
+    // values that fail conversion or validation are not applied
+    assert!(!limits.apply_some(PartialLimits {
+        workers: Some(1000),
+        port: Some(80),
+    }));
+
+    assert_eq!(limits.workers, 4);
+    assert_eq!(limits.port, 8080);
+}
+
+#[derive(Partial)]
+#[partially(derive(Default), error = "ConfigError")]
+struct Outer {
+    name: String,
+    #[partially(nested)]
+    inner: Inner,
+}
+
+#[derive(Partial, Clone)]
+#[partially(derive(Default), error = "ConfigError")]
+struct Inner {
+    host: String,
+    #[partially(validate = "validate_port")]
+    port: u16,
+}
+
+#[test]
+fn try_apply_some_nested() {
+    let mut outer = Outer {
+        name: "initial".to_string(),
+        inner: Inner {
+            host: "localhost".to_string(),
+            port: 8080,
+        },
+    };
+
+    assert_eq!(
+        outer.try_apply_some(PartialOuter {
+            name: None,
+            inner: Some(PartialInner {
+                host: None,
+                port: Some(9090),
+            }),
+        }),
+        Ok(true)
+    );
+
+    assert_eq!(outer.inner.host, "localhost");
+    assert_eq!(outer.inner.port, 9090);
+}
+
+#[test]
+fn try_apply_some_nested_validation_error() {
+    let mut outer = Outer {
+        name: "initial".to_string(),
+        inner: Inner {
+            host: "localhost".to_string(),
+            port: 8080,
+        },
+    };
+
+    assert_eq!(
+        outer.try_apply_some(PartialOuter {
+            name: Some("modified".to_string()),
+            inner: Some(PartialInner {
+                host: Some("example.com".to_string()),
+                port: Some(80),
+            }),
+        }),
+        Err(ConfigError::ReservedPort(80))
+    );
+
+    // nothing is applied when a nested field fails
+    assert_eq!(outer.name, "initial");
+    assert_eq!(outer.inner.host, "localhost");
+    assert_eq!(outer.inner.port, 8080);
+}
@@ -0,0 +1,64 @@
+use partially::{FieldNames, Partial, SetField};
+
+#[derive(Partial, Debug, Default, PartialEq)]
+#[partially(derive(Debug, Default, PartialEq))]
+struct Rgb(u8, u8, u8);
+
+#[derive(Partial, Debug, Default, PartialEq)]
+#[partially(derive(Debug, Default, PartialEq))]
+#[partially(try_from, field_names, set_field)]
+struct Pixel(
+    #[partially(omit)] u64,
+    #[partially(transparent)] Option<String>,
+    #[partially(as_type = "Option<u8>")] u32,
+    Rgb,
+);
+
+#[test]
+fn tuple_apply_some() {
+    let mut rgb = Rgb(1, 2, 3);
+
+    assert!(!rgb.apply_some(PartialRgb::default()));
+    assert_eq!(rgb, Rgb(1, 2, 3));
+
+    assert!(rgb.apply_some(PartialRgb(None, Some(20), Some(30))));
+    assert_eq!(rgb, Rgb(1, 20, 30));
+}
+
+#[test]
+fn tuple_omit_transparent_as_type() {
+    let mut pixel = Pixel(7, None, 1, Rgb(1, 2, 3));
+
+    assert!(pixel.apply_some(PartialPixel(Some("label".to_string()), Some(2), None)));
+    assert_eq!(pixel, Pixel(7, Some("label".to_string()), 2, Rgb(1, 2, 3)));
+}
+
+#[test]
+fn tuple_try_from() {
+    assert_eq!(
+        Pixel::try_from(PartialPixel(None, Some(2), Some(Rgb(1, 2, 3)))),
+        Ok(Pixel(0, None, 2, Rgb(1, 2, 3)))
+    );
+
+    let missing = Pixel::try_from(PartialPixel(None, None, None)).unwrap_err();
+
+    assert!(missing.missing().unwrap().iter().eq(["1", "2"]));
+}
+
+#[test]
+fn tuple_field_names() {
+    assert_eq!(PartialPixel::FIELD_NAMES, ["0", "1", "2"]);
+
+    let partial = PartialPixel(None, Some(2), None);
+
+    assert_eq!(partial.some_field_names().collect::<Vec<_>>(), ["1"]);
+}
+
+#[test]
+fn tuple_set_field() {
+    let mut partial = PartialPixel::default();
+
+    partial.set_field("1", Box::new(2u8)).unwrap();
+
+    assert_eq!(partial, PartialPixel(None, Some(2), None));
+}
@@ -0,0 +1,148 @@
+use partially::{Partial, PartialUndo};
+
+#[derive(Partial, Debug, PartialEq)]
+#[partially(derive(Debug, Default, PartialEq), undo)]
+struct Data {
+    a: String,
+    b: String,
+}
+
+#[test]
+fn undo_records_previous() {
+    let mut data = Data {
+        a: "initial".to_string(),
+        b: "initial".to_string(),
+    };
+
+    let undo = data.apply_some_with_undo(PartialData {
+        a: Some("modified".to_string()),
+        b: None,
+    });
+
+    assert_eq!(
+        undo,
+        PartialData {
+            a: Some("initial".to_string()),
+            b: None,
+        }
+    );
+    assert_eq!(data.a, "modified");
+}
+
+#[test]
+fn undo_restores() {
+    let mut data = Data {
+        a: "initial".to_string(),
+        b: "initial".to_string(),
+    };
+
+    let undo = data.apply_some_with_undo(PartialData {
+        a: Some("modified".to_string()),
+        b: Some("modified".to_string()),
+    });
+
+    data.apply_some(undo);
+
+    assert_eq!(
+        data,
+        Data {
+            a: "initial".to_string(),
+            b: "initial".to_string(),
+        }
+    );
+}
+
+#[derive(Partial)]
+#[partially(derive(Debug, Default, PartialEq), undo)]
+struct Renamed {
+    #[partially(rename = "title")]
+    name: String,
+    #[partially(omit)]
+    revision: u32,
+}
+
+#[test]
+fn undo_renamed() {
+    let mut renamed = Renamed {
+        name: "initial".to_string(),
+        revision: 1,
+    };
+
+    let undo = renamed.apply_some_with_undo(PartialRenamed {
+        title: Some("modified".to_string()),
+    });
+
+    assert_eq!(
+        undo,
+        PartialRenamed {
+            title: Some("initial".to_string()),
+        }
+    );
+    assert_eq!(renamed.name, "modified");
+    assert_eq!(renamed.revision, 1);
+}
+
+#[derive(Partial)]
+#[partially(derive(Debug, Default, PartialEq), undo)]
+struct Outer {
+    #[partially(nested)]
+    inner: Inner,
+}
+
+#[derive(Partial)]
+#[partially(derive(Debug, Default, PartialEq), undo)]
+struct Inner {
+    a: u16,
+    b: u16,
+}
+
+#[test]
+fn undo_nested() {
+    let mut outer = Outer {
+        inner: Inner { a: 1, b: 1 },
+    };
+
+    let undo = outer.apply_some_with_undo(PartialOuter {
+        inner: Some(PartialInner {
+            a: None,
+            b: Some(2),
+        }),
+    });
+
+    // only the nested fields that were applied are recorded
+    assert_eq!(
+        undo,
+        PartialOuter {
+            inner: Some(PartialInner {
+                a: None,
+                b: Some(1),
+            }),
+        }
+    );
+    assert_eq!(outer.inner.b, 2);
+}
+
+#[derive(Partial)]
+#[partially(derive(Debug, Default, PartialEq), undo, error = "String")]
+struct Pool {
+    #[partially(validate = "validate_workers")]
+    workers: u8,
+}
+
+fn validate_workers(value: &u8) -> Result<(), String> {
+    if *value > 0 {
+        Ok(())
+    } else {
+        Err("workers must be positive".to_string())
+    }
+}
+
+#[test]
+fn undo_skips_invalid() {
+    let mut pool = Pool { workers: 1 };
+
+    let undo = pool.apply_some_with_undo(PartialPool { workers: Some(0) });
+
+    assert_eq!(undo, PartialPool::default());
+    assert_eq!(pool.workers, 1);
+}
@@ -0,0 +1,124 @@
+use partially::{Partial, PartialOption};
+
+#[derive(Clone, Debug, Default, PartialEq)]
+struct Base {
+    value: String,
+}
+
+#[derive(Default)]
+struct PartialBase {
+    value: Option<String>,
+}
+
+impl partially::Partial for Base {
+    type Item = PartialBase;
+
+    fn apply_some(&mut self, partial: Self::Item) -> bool {
+        let will_apply_some = partial.value.is_some();
+
+        if let Some(value) = partial.value {
+            self.value = value;
+        }
+
+        will_apply_some
+    }
+}
+
+fn make_partial() -> PartialBase {
+    PartialBase {
+        value: Some("modified".to_string()),
+    }
+}
+
+fn make_base() -> Base {
+    Base {
+        value: "initial".to_string(),
+    }
+}
+
+#[test]
+fn option_apply() {
+    let mut data = Some(make_base());
+
+    assert!(data.apply_some(make_partial()));
+
+    assert_eq!(data.unwrap().value, "modified");
+}
+
+#[test]
+fn option_none_apply() {
+    let mut data: Option<Base> = None;
+
+    assert!(!data.apply_some(make_partial()));
+
+    assert_eq!(data, None);
+}
+
+#[test]
+fn option_apply_or_insert() {
+    let mut data: Option<Base> = None;
+
+    assert!(data.apply_some_or_insert_with(PartialBase::default(), make_base));
+
+    assert_eq!(data, Some(make_base()));
+
+    assert!(!data.apply_some_or_insert_with(PartialBase::default(), make_base));
+
+    let mut data: Option<Base> = None;
+
+    assert!(data.apply_some_or_default(make_partial()));
+
+    assert_eq!(data.unwrap().value, "modified");
+}
+
+#[test]
+fn mut_ref_apply() {
+    // ensures the `&mut T` implementation is used, rather than `T` through auto-deref
+    fn apply<P: Partial>(mut data: P, partial: P::Item) -> bool {
+        data.apply_some(partial)
+    }
+
+    let mut base = make_base();
+
+    assert!(apply(&mut base, make_partial()));
+
+    assert_eq!(base.value, "modified");
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn box_apply() {
+    let mut data = Box::new(make_base());
+
+    assert!(data.apply_some(make_partial()));
+
+    assert_eq!(data.value, "modified");
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn rc_apply() {
+    use std::rc::Rc;
+
+    let mut data = Rc::new(make_base());
+    let shared = data.clone();
+
+    assert!(data.apply_some(make_partial()));
+
+    assert_eq!(data.value, "modified");
+    assert_eq!(shared.value, "initial");
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn arc_apply() {
+    use std::sync::Arc;
+
+    let mut data = Arc::new(make_base());
+    let shared = data.clone();
+
+    assert!(data.apply_some(make_partial()));
+
+    assert_eq!(data.value, "modified");
+    assert_eq!(shared.value, "initial");
+}
@@ -0,0 +1,470 @@
+#![cfg(all(feature = "serde_json", feature = "derive"))]
+
+use partially::{
+    json::{
+        apply_json_patch, apply_merge_patch, diff_json_patch, from_merge_patch, to_json_patch,
+        to_merge_patch, JsonPatchError, PatchOperation,
+    },
+    Partial, Patch,
+};
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+#[derive(Partial, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[partially(derive(Debug, Default, PartialEq, Serialize, Deserialize))]
+#[partially(json)]
+#[partially(diff)]
+#[partially(attribute(serde(default)))]
+#[serde(rename_all = "camelCase")]
+struct Article {
+    title: String,
+    #[partially(patch)]
+    phone_number: Option<String>,
+    #[partially(nested)]
+    author: Author,
+    tags: Vec<String>,
+    content: String,
+}
+
+#[derive(Partial, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[partially(derive(Debug, Default, PartialEq, Serialize, Deserialize))]
+#[partially(json)]
+#[partially(diff)]
+#[partially(attribute(serde(default)))]
+#[serde(rename_all = "camelCase")]
+struct Author {
+    #[partially(patch)]
+    given_name: Option<String>,
+    #[partially(patch)]
+    family_name: Option<String>,
+}
+
+#[derive(Partial, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[partially(derive(Debug, Default, PartialEq, Serialize, Deserialize))]
+#[partially(json)]
+#[partially(attribute(serde(default)))]
+struct Document {
+    #[serde(rename = "a/b")]
+    #[partially(nested)]
+    escaped: Escaped,
+    labels: BTreeMap<String, Option<String>>,
+    author: Author,
+}
+
+#[derive(Partial, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[partially(derive(Debug, Default, PartialEq, Serialize, Deserialize))]
+#[partially(json)]
+#[partially(attribute(serde(default)))]
+struct Escaped {
+    #[serde(rename = "c~d")]
+    value: u64,
+}
+
+/// The target document from RFC 7386, section 3.
+fn make_target() -> Value {
+    json!({
+        "title": "Goodbye!",
+        "author": {
+            "givenName": "John",
+            "familyName": "Doe"
+        },
+        "tags": ["example", "sample"],
+        "content": "This will be unchanged"
+    })
+}
+
+/// The patch document from RFC 7386, section 3.
+fn make_patch() -> Value {
+    json!({
+        "title": "Hello!",
+        "phoneNumber": "+01-123-456-7890",
+        "author": {
+            "familyName": null
+        },
+        "tags": ["example"]
+    })
+}
+
+#[test]
+fn from_merge_patch_rfc_example() {
+    let partial: PartialArticle = from_merge_patch(make_patch()).unwrap();
+
+    assert_eq!(
+        partial,
+        PartialArticle {
+            title: Some("Hello!".to_string()),
+            phone_number: Patch::Value("+01-123-456-7890".to_string()),
+            author: Some(PartialAuthor {
+                given_name: Patch::Missing,
+                family_name: Patch::Null,
+            }),
+            tags: Some(vec!["example".to_string()]),
+            content: None,
+        }
+    );
+}
+
+#[test]
+fn from_merge_patch_rejects_null_without_patch() {
+    // `title` doesn't use the `patch` field option, so it can't be removed
+    let err = from_merge_patch::<PartialArticle>(json!({ "title": null })).unwrap_err();
+
+    assert!(err.to_string().contains("`/title`"));
+}
+
+#[test]
+fn from_merge_patch_rejects_unknown_members() {
+    let err =
+        from_merge_patch::<PartialArticle>(json!({ "author": { "nickname": "JD" } })).unwrap_err();
+
+    assert!(err.to_string().contains("`/author/nickname`"));
+}
+
+#[test]
+fn to_merge_patch_round_trip() {
+    let partial: PartialArticle = from_merge_patch(make_patch()).unwrap();
+
+    assert_eq!(to_merge_patch(&partial).unwrap(), make_patch());
+}
+
+#[test]
+fn to_merge_patch_empty() {
+    assert_eq!(
+        to_merge_patch(&PartialArticle::default()).unwrap(),
+        json!({})
+    );
+}
+
+#[test]
+fn apply_some_matches_apply_merge_patch() {
+    let mut article: Article = serde_json::from_value(make_target()).unwrap();
+    let partial: PartialArticle = from_merge_patch(make_patch()).unwrap();
+
+    assert!(article.apply_some(partial));
+
+    let mut expected = make_target();
+    apply_merge_patch(&mut expected, make_patch());
+
+    assert_eq!(article, serde_json::from_value(expected).unwrap());
+}
+
+#[test]
+fn apply_merge_patch_rfc_example() {
+    let mut target = make_target();
+
+    apply_merge_patch(&mut target, make_patch());
+
+    assert_eq!(
+        target,
+        json!({
+            "title": "Hello!",
+            "author": {
+                "givenName": "John"
+            },
+            "tags": ["example"],
+            "content": "This will be unchanged",
+            "phoneNumber": "+01-123-456-7890"
+        })
+    );
+}
+
+#[test]
+fn apply_merge_patch_rfc_test_cases() {
+    // the test cases from RFC 7386, appendix A, as (original, patch, result)
+    let cases = [
+        (json!({"a": "b"}), json!({"a": "c"}), json!({"a": "c"})),
+        (
+            json!({"a": "b"}),
+            json!({"b": "c"}),
+            json!({"a": "b", "b": "c"}),
+        ),
+        (json!({"a": "b"}), json!({"a": null}), json!({})),
+        (
+            json!({"a": "b", "b": "c"}),
+            json!({"a": null}),
+            json!({"b": "c"}),
+        ),
+        (json!({"a": ["b"]}), json!({"a": "c"}), json!({"a": "c"})),
+        (json!({"a": "c"}), json!({"a": ["b"]}), json!({"a": ["b"]})),
+        (
+            json!({"a": {"b": "c"}}),
+            json!({"a": {"b": "d", "c": null}}),
+            json!({"a": {"b": "d"}}),
+        ),
+        (
+            json!({"a": [{"b": "c"}]}),
+            json!({"a": [1]}),
+            json!({"a": [1]}),
+        ),
+        (json!(["a", "b"]), json!(["c", "d"]), json!(["c", "d"])),
+        (json!({"a": "b"}), json!(["c"]), json!(["c"])),
+        (json!({"a": "foo"}), json!(null), json!(null)),
+        (json!({"a": "foo"}), json!("bar"), json!("bar")),
+        (
+            json!({"e": null}),
+            json!({"a": 1}),
+            json!({"e": null, "a": 1}),
+        ),
+        (
+            json!([1, 2]),
+            json!({"a": "b", "c": null}),
+            json!({"a": "b"}),
+        ),
+        (
+            json!({}),
+            json!({"a": {"bb": {"ccc": null}}}),
+            json!({"a": {"bb": {}}}),
+        ),
+    ];
+
+    for (mut target, patch, expected) in cases {
+        apply_merge_patch(&mut target, patch);
+
+        assert_eq!(target, expected);
+    }
+}
+
+/// The operations equivalent to the patch document from RFC 7386, section 3.
+fn make_ops() -> Vec<PatchOperation> {
+    vec![
+        PatchOperation::Add {
+            path: "/title".to_string(),
+            value: json!("Hello!"),
+        },
+        PatchOperation::Add {
+            path: "/phoneNumber".to_string(),
+            value: json!("+01-123-456-7890"),
+        },
+        PatchOperation::Remove {
+            path: "/author/familyName".to_string(),
+        },
+        PatchOperation::Add {
+            path: "/tags".to_string(),
+            value: json!(["example"]),
+        },
+    ]
+}
+
+#[test]
+fn to_json_patch_rfc_example() {
+    let partial: PartialArticle = from_merge_patch(make_patch()).unwrap();
+
+    let mut ops = to_json_patch(&partial).unwrap();
+    ops.sort_by(|a, b| format!("{:?}", a).cmp(&format!("{:?}", b)));
+
+    let mut expected = make_ops();
+    expected.sort_by(|a, b| format!("{:?}", a).cmp(&format!("{:?}", b)));
+
+    assert_eq!(ops, expected);
+}
+
+#[test]
+fn to_json_patch_escapes_pointers() {
+    let partial = PartialDocument {
+        escaped: Some(PartialEscaped { value: Some(1) }),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_json_patch(&partial).unwrap(),
+        vec![PatchOperation::Add {
+            path: "/a~1b/c~0d".to_string(),
+            value: json!(1),
+        }]
+    );
+}
+
+#[test]
+fn to_json_patch_emits_whole_values() {
+    let partial = PartialDocument {
+        escaped: Some(PartialEscaped::default()),
+        labels: Some(BTreeMap::from([("draft".to_string(), None)])),
+        author: Some(Author {
+            given_name: Some("John".to_string()),
+            family_name: None,
+        }),
+    };
+
+    // only `nested` fields are emitted as operations on their members, so the empty partial emits none
+    assert_eq!(
+        to_json_patch(&partial).unwrap(),
+        vec![
+            PatchOperation::Add {
+                path: "/labels".to_string(),
+                value: json!({ "draft": null }),
+            },
+            PatchOperation::Add {
+                path: "/author".to_string(),
+                value: json!({ "givenName": "John", "familyName": null }),
+            },
+        ]
+    );
+    assert_eq!(
+        to_merge_patch(&partial).unwrap(),
+        json!({
+            "a/b": {},
+            "labels": { "draft": null },
+            "author": { "givenName": "John", "familyName": null }
+        })
+    );
+}
+
+#[test]
+fn apply_json_patch_whole_values() {
+    let mut document = Document {
+        escaped: Escaped { value: 1 },
+        labels: BTreeMap::from([("review".to_string(), Some("pending".to_string()))]),
+        author: Author {
+            given_name: Some("Jane".to_string()),
+            family_name: Some("Doe".to_string()),
+        },
+    };
+
+    let ops = vec![PatchOperation::Add {
+        path: "/author".to_string(),
+        value: json!({ "givenName": "John", "familyName": null }),
+    }];
+
+    // fields that don't use the `nested` field option are replaced as a whole
+    assert!(apply_json_patch(&mut document, ops).unwrap());
+    assert_eq!(
+        document.author,
+        Author {
+            given_name: Some("John".to_string()),
+            family_name: None,
+        }
+    );
+    assert_eq!(document.escaped, Escaped { value: 1 });
+}
+
+#[test]
+fn to_json_patch_serializes_ops() {
+    let ops = vec![
+        PatchOperation::Add {
+            path: "/title".to_string(),
+            value: json!("Hello!"),
+        },
+        PatchOperation::Remove {
+            path: "/author/familyName".to_string(),
+        },
+    ];
+
+    assert_eq!(
+        serde_json::to_value(ops).unwrap(),
+        json!([
+            { "op": "add", "path": "/title", "value": "Hello!" },
+            { "op": "remove", "path": "/author/familyName" }
+        ])
+    );
+}
+
+#[test]
+fn diff_json_patch_changed_fields() {
+    let old: Article = serde_json::from_value(make_target()).unwrap();
+
+    let mut new = old.clone();
+    new.title = "Hello!".to_string();
+    new.author.family_name = None;
+
+    let mut ops = diff_json_patch(&old, &new).unwrap();
+    ops.sort_by(|a, b| format!("{:?}", a).cmp(&format!("{:?}", b)));
+
+    assert_eq!(
+        ops,
+        vec![
+            PatchOperation::Add {
+                path: "/title".to_string(),
+                value: json!("Hello!"),
+            },
+            PatchOperation::Remove {
+                path: "/author/familyName".to_string(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn apply_json_patch_matches_apply_merge_patch() {
+    let mut article: Article = serde_json::from_value(make_target()).unwrap();
+
+    assert!(apply_json_patch(&mut article, make_ops()).unwrap());
+
+    let mut expected = make_target();
+    apply_merge_patch(&mut expected, make_patch());
+
+    assert_eq!(article, serde_json::from_value(expected).unwrap());
+}
+
+#[test]
+fn apply_json_patch_later_ops_win() {
+    let mut article: Article = serde_json::from_value(make_target()).unwrap();
+
+    let ops = vec![
+        PatchOperation::Remove {
+            path: "/author/givenName".to_string(),
+        },
+        PatchOperation::Add {
+            path: "/author/givenName".to_string(),
+            value: json!("Jane"),
+        },
+    ];
+
+    assert!(apply_json_patch(&mut article, ops).unwrap());
+    assert_eq!(article.author.given_name, Some("Jane".to_string()));
+    assert_eq!(article.author.family_name, Some("Doe".to_string()));
+}
+
+#[test]
+fn apply_json_patch_unsupported_operation() {
+    let mut article: Article = serde_json::from_value(make_target()).unwrap();
+
+    let ops = vec![PatchOperation::Test {
+        path: "/title".to_string(),
+        value: json!("Goodbye!"),
+    }];
+
+    assert!(matches!(
+        apply_json_patch(&mut article, ops),
+        Err(JsonPatchError::UnsupportedOperation("test"))
+    ));
+}
+
+#[test]
+fn apply_json_patch_rejects_remove_without_patch() {
+    let mut article: Article = serde_json::from_value(make_target()).unwrap();
+
+    // `title` doesn't use the `patch` field option, so it can't be removed
+    let ops = vec![PatchOperation::Remove {
+        path: "/title".to_string(),
+    }];
+
+    assert!(matches!(
+        apply_json_patch(&mut article, ops),
+        Err(JsonPatchError::InvalidPath(path)) if path == "/title"
+    ));
+    assert_eq!(article, serde_json::from_value(make_target()).unwrap());
+}
+
+#[test]
+fn apply_json_patch_invalid_path() {
+    let mut article: Article = serde_json::from_value(make_target()).unwrap();
+
+    let ops = vec![
+        PatchOperation::Replace {
+            path: "/title".to_string(),
+            value: json!("Hello!"),
+        },
+        PatchOperation::Replace {
+            path: "/title/length".to_string(),
+            value: json!(5),
+        },
+    ];
+
+    assert!(matches!(
+        apply_json_patch(&mut article, ops),
+        Err(JsonPatchError::InvalidPath(path)) if path == "/title/length"
+    ));
+    assert_eq!(article, serde_json::from_value(make_target()).unwrap());
+}
@@ -0,0 +1,158 @@
+#![cfg(feature = "alloc")]
+
+use std::collections::BTreeMap;
+
+use partially::{MapOp, MapPatch, Partial};
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Settings {
+    limit: u32,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct PartialSettings {
+    limit: Option<u32>,
+}
+
+impl partially::Partial for Settings {
+    type Item = PartialSettings;
+
+    fn apply_some(&mut self, partial: Self::Item) -> bool {
+        let will_apply_some = partial.limit.is_some();
+
+        if let Some(limit) = partial.limit {
+            self.limit = limit;
+        }
+
+        will_apply_some
+    }
+}
+
+fn make_patch() -> MapPatch<String, Settings> {
+    MapPatch::new()
+        .with("a".to_string(), MapOp::Set(Settings { limit: 10 }))
+        .with(
+            "b".to_string(),
+            MapOp::Patch(PartialSettings { limit: Some(20) }),
+        )
+        .with("c".to_string(), MapOp::Remove)
+}
+
+fn make_entries() -> [(String, Settings); 3] {
+    [
+        ("a".to_string(), Settings { limit: 1 }),
+        ("b".to_string(), Settings { limit: 2 }),
+        ("c".to_string(), Settings { limit: 3 }),
+    ]
+}
+
+#[test]
+fn map_patch_btree_map() {
+    let mut data = BTreeMap::from(make_entries());
+
+    assert!(data.apply_some(make_patch()));
+
+    assert_eq!(
+        data,
+        BTreeMap::from([
+            ("a".to_string(), Settings { limit: 10 }),
+            ("b".to_string(), Settings { limit: 20 }),
+        ])
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn map_patch_hash_map() {
+    use std::collections::HashMap;
+
+    let mut data = HashMap::from(make_entries());
+
+    assert!(data.apply_some(make_patch()));
+
+    assert_eq!(
+        data,
+        HashMap::from([
+            ("a".to_string(), Settings { limit: 10 }),
+            ("b".to_string(), Settings { limit: 20 }),
+        ])
+    );
+}
+
+#[test]
+fn map_patch_skips_missing() {
+    let mut data: BTreeMap<String, Settings> = BTreeMap::new();
+
+    let patch = MapPatch::new()
+        .with(
+            "a".to_string(),
+            MapOp::Patch(PartialSettings { limit: Some(20) }),
+        )
+        .with("b".to_string(), MapOp::Remove);
+
+    assert!(!data.apply_some(patch));
+
+    assert!(data.is_empty());
+}
+
+#[test]
+fn map_patch_in_order() {
+    let mut data: BTreeMap<String, Settings> = BTreeMap::new();
+
+    let patch: MapPatch<_, _> = [
+        ("a".to_string(), MapOp::Set(Settings { limit: 1 })),
+        (
+            "a".to_string(),
+            MapOp::Patch(PartialSettings { limit: Some(2) }),
+        ),
+    ]
+    .into_iter()
+    .collect();
+
+    assert!(data.apply_some(patch));
+
+    assert_eq!(data["a"], Settings { limit: 2 });
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn map_patch_serde() {
+    let patch: MapPatch<String, Settings> = serde_json::from_str(
+        r#"{
+            "a": { "set": { "limit": 10 } },
+            "b": { "patch": { "limit": 20 } },
+            "c": "remove"
+        }"#,
+    )
+    .unwrap();
+
+    assert_eq!(patch, make_patch());
+
+    assert_eq!(
+        serde_json::to_string(&make_patch()).unwrap(),
+        r#"{"a":{"set":{"limit":10}},"b":{"patch":{"limit":20}},"c":"remove"}"#
+    );
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn map_patch_nested() {
+    #[derive(Partial, Debug, PartialEq)]
+    #[partially(derive(Default))]
+    struct Data {
+        #[partially(nested)]
+        tenants: BTreeMap<String, Settings>,
+    }
+
+    let mut data = Data {
+        tenants: BTreeMap::from(make_entries()),
+    };
+
+    assert!(data.apply_some(PartialData {
+        tenants: Some(make_patch()),
+    }));
+
+    assert_eq!(data.tenants.len(), 2);
+}
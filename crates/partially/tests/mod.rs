@@ -1,4 +1,8 @@
-#[cfg(feature = "derive")]
-mod derive;
-
-mod apply_some;
+#[cfg(feature = "derive")]
+mod derive;
+
+mod apply_some;
+mod impls;
+mod json;
+mod map_patch;
+mod vec_patch;
@@ -0,0 +1,160 @@
+#![cfg(feature = "alloc")]
+
+use partially::{Partial, TryPartial, VecOp, VecPatch, VecPatchError};
+
+fn make_vec() -> Vec<&'static str> {
+    vec!["a", "b", "c"]
+}
+
+#[test]
+fn vec_patch_apply() {
+    let mut data = make_vec();
+
+    let patch = VecPatch::new()
+        .with(VecOp::Push("d"))
+        .with(VecOp::Insert {
+            index: 0,
+            value: "z",
+        })
+        .with(VecOp::Remove(1))
+        .with(VecOp::Replace {
+            index: 0,
+            value: "y",
+        });
+
+    assert!(data.apply_some(patch));
+
+    assert_eq!(data, vec!["y", "b", "c", "d"]);
+}
+
+#[test]
+fn vec_patch_truncate() {
+    let mut data = make_vec();
+
+    assert!(!data.apply_some(VecPatch::new().with(VecOp::Truncate(5))));
+    assert!(data.apply_some(VecPatch::new().with(VecOp::Truncate(1))));
+
+    assert_eq!(data, vec!["a"]);
+}
+
+#[test]
+fn vec_patch_set() {
+    let mut data = make_vec();
+
+    assert!(data.apply_some(VecPatch::new().with(VecOp::Set(vec!["x"]))));
+
+    assert_eq!(data, vec!["x"]);
+}
+
+#[test]
+fn vec_patch_empty() {
+    let mut data = make_vec();
+
+    assert!(!data.apply_some(VecPatch::new()));
+
+    assert_eq!(data, make_vec());
+}
+
+#[test]
+fn vec_patch_skips_out_of_bounds() {
+    let mut data = make_vec();
+
+    let patch = VecPatch::from(vec![VecOp::Remove(3), VecOp::Push("d")]);
+
+    assert!(data.apply_some(patch));
+
+    assert_eq!(data, vec!["a", "b", "c", "d"]);
+}
+
+#[test]
+fn vec_patch_try_apply() {
+    let mut data = make_vec();
+
+    let patch: VecPatch<_> = [VecOp::Push("d"), VecOp::Remove(3)].into_iter().collect();
+
+    assert_eq!(data.try_apply_some(patch), Ok(true));
+
+    assert_eq!(data, make_vec());
+}
+
+#[test]
+fn vec_patch_try_apply_out_of_bounds() {
+    let mut data = make_vec();
+
+    let patch = VecPatch::from(vec![
+        VecOp::Push("d"),
+        VecOp::Truncate(1),
+        VecOp::Replace {
+            index: 1,
+            value: "x",
+        },
+    ]);
+
+    assert_eq!(
+        data.try_apply_some(patch),
+        Err(VecPatchError { index: 1, len: 1 })
+    );
+
+    // nothing is applied when an error is returned
+    assert_eq!(data, make_vec());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn vec_patch_serde() {
+    let patch: VecPatch<String> = serde_json::from_str(
+        r#"[
+            { "push": "d" },
+            { "insert": { "index": 0, "value": "z" } },
+            { "remove": 1 },
+            { "replace": { "index": 0, "value": "y" } },
+            { "truncate": 3 },
+            { "set": ["x"] }
+        ]"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        patch.ops(),
+        &[
+            VecOp::Push("d".to_string()),
+            VecOp::Insert {
+                index: 0,
+                value: "z".to_string()
+            },
+            VecOp::Remove(1),
+            VecOp::Replace {
+                index: 0,
+                value: "y".to_string()
+            },
+            VecOp::Truncate(3),
+            VecOp::Set(vec!["x".to_string()]),
+        ]
+    );
+
+    assert_eq!(
+        serde_json::to_string(&VecPatch::new().with(VecOp::Push(1))).unwrap(),
+        r#"[{"push":1}]"#
+    );
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn vec_patch_nested() {
+    #[derive(Partial, Debug, PartialEq)]
+    #[partially(derive(Default))]
+    struct Data {
+        #[partially(nested)]
+        tags: Vec<String>,
+    }
+
+    let mut data = Data {
+        tags: vec!["a".to_string()],
+    };
+
+    assert!(data.apply_some(PartialData {
+        tags: Some(VecPatch::new().with(VecOp::Push("b".to_string()))),
+    }));
+
+    assert_eq!(data.tags, vec!["a".to_string(), "b".to_string()]);
+}
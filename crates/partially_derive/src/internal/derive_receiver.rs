@@ -1,172 +1,769 @@
-use darling::{
-    ast,
-    util::{Flag, PathList},
-    FromDeriveInput,
-};
-use proc_macro2::TokenStream;
-use quote::{quote, ToTokens};
-use syn::{Attribute, Generics, Ident, Path, Visibility};
-
-use super::{
-    field_receiver::FieldReceiver,
-    impl_partial::ImplPartial,
-    meta_attribute::MetaAttribute,
-    token_vec::{Separator, TokenVec},
-};
-
-#[derive(Debug, FromDeriveInput)]
-#[darling(attributes(partially), supports(struct_any), forward_attrs)]
-pub struct DeriveReceiver {
-    /// The struct ident.
-    pub ident: Ident,
-
-    /// Get the visibility of the field.
-    pub vis: Visibility,
-
-    /// Get the attributes of the field.
-    pub attrs: Vec<Attribute>,
-
-    /// The type's generics. You'll need these any time your trait is expected
-    /// to work with types that declare generics.
-    pub generics: Generics,
-
-    /// Receives the body of the struct. We don't care about
-    /// struct fields because we previously told darling we only accept structs.
-    pub data: ast::Data<(), FieldReceiver>,
-
-    /// Receives an optional identifer to use for the generated struct.
-    ///
-    /// Note: By default, `Partial` + [`Self::ident`] is used.  
-    pub rename: Option<Ident>,
-
-    /// Receives an optional [`PathList`] defining the various
-    /// derive entries for the generated struct to `#[derive()]`
-    ///
-    /// Note: By default, [`None`].
-    ///
-    /// Note: This is technically just a specific sub-case of [`Self::additional_attrs`]
-    /// but exists on it's own as a cleaner shortcut.
-    pub derive: Option<PathList>,
-
-    /// Recieves a [`Vec<Meta>`] containing entries to
-    /// prepend as attributes to the generated struct.
-    ///
-    /// For example: `#[partially(attribute(serde(default))]` would result in `#[serde(default)]`
-    /// being appended to the generated struct.
-    #[darling(rename = "attribute", multiple)]
-    pub additional_attrs: Vec<MetaAttribute>,
-
-    /// Recieves an optional flag that indicates we should not forward attributes
-    /// from the original struct to the generated struct.
-    ///
-    /// Note: By default, `false` - meaning __we will forward attributes__.
-    ///
-    /// Note: If [`Self::additional_attrs`] is present, those attributes __will still be added__
-    /// to the generated struct.
-    ///
-    /// Note: If [`Self::derive`] is present, that attributes __will still be added__ to
-    /// the generated struct.
-    #[darling(rename = "skip_attributes")]
-    pub skip_attrs: Flag,
-
-    /// Receives an optional [`Path`] defining the path to the `partially` crate.
-    #[darling(rename = "crate")]
-    pub krate: Option<Path>,
-}
-
-impl ToTokens for DeriveReceiver {
-    fn to_tokens(&self, tokens: &mut TokenStream) {
-        let DeriveReceiver {
-            ref ident,
-            ref vis,
-            ref attrs,
-            ref generics,
-            ref data,
-            ref rename,
-            ref derive,
-            ref additional_attrs,
-            ref skip_attrs,
-            ref krate,
-        } = *self;
-
-        let (_, ty, wher) = generics.split_for_impl();
-
-        let fields: Vec<_> = data
-            .as_ref()
-            .take_struct()
-            .expect("expected a struct")
-            .fields
-            .into_iter()
-            .filter(|f| !f.omit.is_present())
-            .collect();
-
-        let to_ident = if let Some(rename) = &rename {
-            rename.to_owned()
-        } else {
-            let title = format!("Partial{}", ident);
-            Ident::new(&title, ident.span())
-        };
-
-        // handle custom derive attr
-        if let Some(derive_paths) = derive {
-            let derive_paths =
-                TokenVec::new_with_vec_and_sep(derive_paths.to_vec(), Separator::Comma);
-            tokens.extend(quote! {
-                    #[derive(#derive_paths)]
-            });
-        }
-
-        // handle non-derive attrs
-        if !skip_attrs.is_present() {
-            for attr in attrs {
-                if !attr.path().is_ident("derive") {
-                    tokens.extend(quote!(#attr))
-                }
-            }
-        }
-
-        // handle additional attrs
-        let additional_attrs =
-            TokenVec::new_with_vec_and_sep(additional_attrs.to_vec(), Separator::Newline);
-        tokens.extend(quote! {
-            #additional_attrs
-        });
-
-        let field_tokens = TokenVec::new_with_vec_and_sep(fields.clone(), Separator::CommaNewline);
-
-        // write the struct
-        tokens.extend(quote! {
-            #vis struct #to_ident #ty #wher {
-                #field_tokens
-            }
-        });
-
-        // create the impl
-        let impl_partial = ImplPartial {
-            krate,
-            from_ident: ident,
-            to_ident: &to_ident,
-            generics,
-            fields: &fields,
-        };
-
-        // write the impl
-        tokens.extend(quote! {
-            #impl_partial
-        });
-
-        // create the partial => partial impl
-        let partial_impl_partial = ImplPartial {
-            krate,
-            from_ident: &to_ident,
-            to_ident: &to_ident,
-            generics,
-            fields: &fields,
-        };
-
-        // write it
-        tokens.extend(quote! {
-            #partial_impl_partial
-        })
-    }
-}
+use darling::{
+    ast,
+    util::{Flag, PathList},
+    FromDeriveInput,
+};
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::{Attribute, Generics, Ident, Path, Type, Visibility, WherePredicate};
+
+use super::{
+    field_receiver::FieldReceiver,
+    impl_diff::ImplDiff,
+    impl_field_names::ImplFieldNames,
+    impl_from_env::ImplFromEnv,
+    impl_merge::ImplMerge,
+    impl_partial::ImplPartial,
+    impl_partial_enum::ImplPartialEnum,
+    impl_report::{ImplReport, MAX_REPORT_FIELDS},
+    impl_set_field::ImplSetField,
+    impl_try_from::{ImplTryFrom, MAX_TRY_FROM_FIELDS},
+    impl_try_partial::ImplTryPartial,
+    impl_undo::ImplUndo,
+    meta_attribute::MetaAttribute,
+    phantom_marker::PhantomMarker,
+    token_vec::{Separator, TokenVec},
+    variant_receiver::VariantReceiver,
+};
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(
+    attributes(partially),
+    supports(struct_any, enum_any),
+    forward_attrs,
+    and_then = DeriveReceiver::validate
+)]
+pub struct DeriveReceiver {
+    /// The struct ident.
+    pub ident: Ident,
+
+    /// Get the visibility of the field.
+    pub vis: Visibility,
+
+    /// Get the attributes of the field.
+    pub attrs: Vec<Attribute>,
+
+    /// The type's generics. You'll need these any time your trait is expected
+    /// to work with types that declare generics.
+    pub generics: Generics,
+
+    /// Receives the body of the struct, or the variants of the enum.
+    pub data: ast::Data<VariantReceiver, FieldReceiver>,
+
+    /// Receives an optional identifer to use for the generated struct.
+    ///
+    /// Note: By default, `Partial` + [`Self::ident`] is used.  
+    pub rename: Option<Ident>,
+
+    /// Receives an optional [`PathList`] defining the various
+    /// derive entries for the generated struct to `#[derive()]`
+    ///
+    /// Note: By default, [`None`].
+    ///
+    /// Note: This is technically just a specific sub-case of [`Self::additional_attrs`]
+    /// but exists on it's own as a cleaner shortcut.
+    pub derive: Option<PathList>,
+
+    /// Recieves a [`Vec<Meta>`] containing entries to
+    /// prepend as attributes to the generated struct.
+    ///
+    /// For example: `#[partially(attribute(serde(default))]` would result in `#[serde(default)]`
+    /// being appended to the generated struct.
+    #[darling(rename = "attribute", multiple)]
+    pub additional_attrs: Vec<MetaAttribute>,
+
+    /// Recieves an optional flag that indicates we should not forward attributes
+    /// from the original struct to the generated struct.
+    ///
+    /// Note: By default, `false` - meaning __we will forward attributes__.
+    ///
+    /// Note: If [`Self::additional_attrs`] is present, those attributes __will still be added__
+    /// to the generated struct.
+    ///
+    /// Note: If [`Self::derive`] is present, that attributes __will still be added__ to
+    /// the generated struct.
+    #[darling(rename = "skip_attributes")]
+    pub skip_attrs: Flag,
+
+    /// Receives an optional [`Path`] defining the path to the `partially` crate.
+    #[darling(rename = "crate")]
+    pub krate: Option<Path>,
+
+    /// Receives an optional [`Type`] to use as the error type of a generated `TryPartial` implementation.
+    ///
+    /// Note: By default, [`None`] - meaning __no `TryPartial` implementation is generated__.
+    pub error: Option<Type>,
+
+    /// Recieves an optional flag that indicates we should generate a `Diff` implementation.
+    ///
+    /// Note: By default, `false` - meaning __no `Diff` implementation is generated__.
+    ///
+    /// Note: The generated implementation requires that each field implements `PartialEq` and `Clone`.
+    pub diff: Flag,
+
+    /// Recieves an optional flag that indicates we should generate a `Merge` implementation for the generated struct.
+    ///
+    /// Note: By default, `false` - meaning __no `Merge` implementation is generated__.
+    ///
+    /// Note: The generated implementation requires that each field implements `PartialEq`.
+    pub merge: Flag,
+
+    /// Recieves an optional flag that indicates we should generate a `TryFrom` implementation, converting
+    /// the generated struct into the base struct.
+    ///
+    /// Note: By default, `false` - meaning __no `TryFrom` implementation is generated__.
+    ///
+    /// Note: `omit`-ted fields are filled with their `default` expression, or `Default::default()`.
+    ///
+    /// Note: At most [`MAX_TRY_FROM_FIELDS`] fields that may be missing (neither `omit`-ted nor `transparent`) are supported.
+    pub try_from: Flag,
+
+    /// Recieves an optional flag that indicates we should generate a `PartialReport` implementation, along
+    /// with a `{ident}Fields` set type used as its report.
+    ///
+    /// Note: By default, `false` - meaning __no `PartialReport` implementation is generated__.
+    ///
+    /// Note: At most [`MAX_REPORT_FIELDS`] non-`omit`-ted fields are supported.
+    pub report: Flag,
+
+    /// Recieves an optional flag that indicates we should compare values with the current values of the base
+    /// struct, such that only fields whose value changes are applied (and reported).
+    ///
+    /// Note: By default, `false` - meaning __every [`Some`] value is applied__.
+    ///
+    /// Note: The generated implementations require that each field implements `PartialEq`.
+    pub compare: Flag,
+
+    /// Recieves an optional flag that indicates we should generate a `PartialUndo` implementation.
+    ///
+    /// Note: By default, `false` - meaning __no `PartialUndo` implementation is generated__.
+    pub undo: Flag,
+
+    /// Recieves an optional flag that indicates we should add `#[serde(default, skip_serializing_if = ..)]`
+    /// attributes to the generated fields, such that absent values are not serialized.
+    ///
+    /// Note: By default, `false` - meaning __absent values are serialized (usually as `null`)__.
+    ///
+    /// Note: Only fields using `patch`, or whose generated type is an [`Option`] (including `as_type` and
+    /// `transparent` fields), are affected.
+    pub serde_sparse: Flag,
+
+    /// Recieves an optional flag that indicates we should generate a `FieldNames` implementation for the generated struct.
+    ///
+    /// Note: By default, `false` - meaning __no `FieldNames` implementation is generated__.
+    pub field_names: Flag,
+
+    /// Recieves an optional flag that indicates we should generate a `SetField` implementation for the generated struct.
+    ///
+    /// Note: By default, `false` - meaning __no `SetField` implementation is generated__.
+    ///
+    /// Note: The generated implementation requires the `alloc` feature of `partially`, and that each field is `'static`.
+    pub set_field: Flag,
+
+    /// Recieves an optional flag that indicates we should generate a `FromEnv` implementation for the generated struct.
+    ///
+    /// Note: By default, `false` - meaning __no `FromEnv` implementation is generated__.
+    ///
+    /// Note: The generated implementation requires the `alloc` feature of `partially`, and that each field
+    /// implements `FromStr` (or `FromEnv` for `nested` fields).
+    pub env: Flag,
+
+    /// Recieves an optional flag that indicates the generated struct should derive `clap::Args`, with each field
+    /// as an optional `--long` flag.
+    ///
+    /// Note: By default, `false` - meaning __no `clap` attributes are added__.
+    ///
+    /// Note: `nested` fields are flattened (using `#[command(flatten)]`), and must also use `clap`.
+    pub clap: Flag,
+
+    /// Recieves an optional flag that indicates `nested` and `patch` fields should be serialized such that the `json`
+    /// module of `partially` recognizes them.
+    ///
+    /// Note: By default, `false` - meaning __`nested` and `patch` fields are serialized as is__.
+    ///
+    /// Note: Requires deriving `Serialize` for the generated struct (using the `derive` option), and the `serde`
+    /// feature of `partially`.
+    pub json: Flag,
+
+    /// Receives an optional list of where-clause predicates to use for the generated struct and each generated
+    /// implementation, such as `T: Clone`.
+    ///
+    /// Note: By default, [`None`] - meaning __the where-clause of the base struct is used__.
+    ///
+    /// Note: These replace the where-clause of the base struct, while the `bound` option of each field extends it.
+    pub bound: Option<Vec<WherePredicate>>,
+}
+
+impl DeriveReceiver {
+    fn validate(self) -> darling::Result<Self> {
+        let mut acc = darling::Error::accumulator();
+
+        // the fields of each variant are validated like the fields of a struct
+        let fields = self.all_fields();
+
+        for field in fields.iter().filter(|f| !f.omit.is_present()) {
+            if self.error.is_none() && field.is_fallible() {
+                acc.push(
+                    darling::Error::custom("cannot use try_into or validate without error")
+                        .with_span(&field.ty),
+                );
+            }
+
+            if self.diff.is_present() && field.as_type.is_some() {
+                acc.push(
+                    darling::Error::custom("cannot use as_type with diff").with_span(&field.ty),
+                );
+            }
+
+            if self.undo.is_present() && (field.transparent.is_some() || field.as_type.is_some()) {
+                acc.push(
+                    darling::Error::custom("cannot use transparent or as_type with undo")
+                        .with_span(&field.ty),
+                );
+            }
+
+            if self.clap.is_present() && field.patch.is_present() {
+                acc.push(darling::Error::custom("cannot use patch with clap").with_span(&field.ty));
+            }
+
+            // clap requires arguments that aren't an `Option` to be present, so a partial couldn't omit them
+            if self.clap.is_present() && !field.patch.is_present() && !field.is_dst_option() {
+                acc.push(
+                    darling::Error::custom(
+                        "cannot use transparent or as_type with clap, unless the type is an Option",
+                    )
+                    .with_span(&field.ty),
+                );
+            }
+
+            if self.env.is_present() && !field.patch.is_present() && !field.is_dst_option() {
+                acc.push(
+                    darling::Error::custom(
+                        "cannot use transparent or as_type with env, unless the type is an Option",
+                    )
+                    .with_span(&field.ty),
+                );
+            }
+        }
+
+        if self.data.is_enum() {
+            let unsupported = [
+                ("error", self.error.is_some()),
+                ("diff", self.diff.is_present()),
+                ("merge", self.merge.is_present()),
+                ("try_from", self.try_from.is_present()),
+                ("report", self.report.is_present()),
+                ("undo", self.undo.is_present()),
+                ("field_names", self.field_names.is_present()),
+                ("set_field", self.set_field.is_present()),
+                ("env", self.env.is_present()),
+                ("clap", self.clap.is_present()),
+                ("json", self.json.is_present()),
+            ];
+
+            for (name, is_present) in unsupported {
+                if is_present {
+                    acc.push(
+                        darling::Error::custom(format!("cannot use {} with an enum", name))
+                            .with_span(&self.ident),
+                    );
+                }
+            }
+        }
+
+        if self.clap.is_present()
+            && matches!(&self.data, ast::Data::Struct(fields) if fields.is_tuple())
+        {
+            acc.push(
+                darling::Error::custom("cannot use clap with a tuple struct")
+                    .with_span(&self.ident),
+            );
+        }
+
+        if self.json.is_present() && !self.derives("Serialize") {
+            acc.push(
+                darling::Error::custom("cannot use json without deriving Serialize")
+                    .with_span(&self.ident),
+            );
+        }
+
+        if self.report.is_present() {
+            let len = fields.iter().filter(|f| !f.omit.is_present()).count();
+
+            if len > MAX_REPORT_FIELDS {
+                acc.push(
+                    darling::Error::custom(format!(
+                        "cannot use report with more than {} fields",
+                        MAX_REPORT_FIELDS
+                    ))
+                    .with_span(&self.ident),
+                );
+            }
+        }
+
+        if self.try_from.is_present() {
+            let len = fields
+                .iter()
+                .filter(|f| !f.omit.is_present() && f.transparent.is_none())
+                .count();
+
+            if len > MAX_TRY_FROM_FIELDS {
+                acc.push(
+                    darling::Error::custom(format!(
+                        "cannot use try_from with more than {} fields that may be missing",
+                        MAX_TRY_FROM_FIELDS
+                    ))
+                    .with_span(&self.ident),
+                );
+            }
+        }
+
+        let krate = self.krate.clone();
+        let serde_sparse = self.serde_sparse.is_present();
+        let serde_serialize = self.derives("Serialize");
+        let serde_deserialize = self.derives("Deserialize");
+        let clap = self.clap.is_present();
+        let json = self.json.is_present();
+
+        // fields of tuple structs are accessed by position, which shifts in the generated struct for omitted fields
+        let mut index = 0;
+        let mut dst_index = 0;
+
+        // fields need the crate path to name the `Partial` trait, and the serde, clap and json options, so we forward them
+        // (`patch` fields add serde attributes for the traits the generated struct derives)
+        let data = self
+            .data
+            .map_enum_variants(|variant| {
+                variant.with_fields(&krate, serde_sparse, serde_serialize, serde_deserialize)
+            })
+            .map_struct_fields(|mut field| {
+                field.index = index;
+                field.dst_index = dst_index;
+                index += 1;
+                if !field.omit.is_present() {
+                    dst_index += 1;
+                }
+
+                field.krate = krate.clone();
+                field.serde_sparse = serde_sparse;
+                field.serde_serialize = serde_serialize;
+                field.serde_deserialize = serde_deserialize;
+                field.clap = clap;
+                field.json = json;
+                field
+            });
+
+        acc.finish_with(Self { data, ..self })
+    }
+
+    /// Gets whether the generated struct derives a trait named `name` (matching the last segment of the path).
+    fn derives(&self, name: &str) -> bool {
+        self.derive
+            .iter()
+            .flat_map(|paths| paths.iter())
+            .any(|path| {
+                path.segments
+                    .last()
+                    .is_some_and(|segment| segment.ident == name)
+            })
+    }
+
+    /// Gets the fields of the struct, or the fields of each variant of the enum.
+    fn all_fields(&self) -> Vec<&FieldReceiver> {
+        match &self.data {
+            ast::Data::Struct(fields) => fields.iter().collect(),
+            ast::Data::Enum(variants) => variants.iter().flat_map(|v| v.fields.iter()).collect(),
+        }
+    }
+
+    /// Gets the generics to use for the generated struct, or for generated implementations, using the container's
+    /// `bound` for the where-clause (when present), extended with the `bound` of each field.
+    ///
+    /// Note: When `is_base` is `true`, the generics are used for implementations that name the base struct, which
+    /// requires the where-clause of the base struct - so the container's `bound` extends it, rather than replacing it.
+    fn to_bounded_generics(&self, is_base: bool) -> Generics {
+        let mut generics = self.generics.clone();
+
+        let fields = self.all_fields();
+
+        if self.bound.is_none() && fields.iter().all(|f| f.bound.is_none()) {
+            return generics;
+        }
+
+        let predicates = &mut generics.make_where_clause().predicates;
+
+        if let Some(bound) = &self.bound {
+            if !is_base {
+                predicates.clear();
+            }
+            predicates.extend(bound.iter().cloned());
+        }
+
+        for field in fields {
+            if let Some(bound) = &field.bound {
+                predicates.extend(bound.iter().cloned());
+            }
+        }
+
+        generics
+    }
+}
+
+impl ToTokens for DeriveReceiver {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let DeriveReceiver {
+            ref ident,
+            ref vis,
+            ref attrs,
+            generics: _,
+            ref data,
+            ref rename,
+            ref derive,
+            ref additional_attrs,
+            ref skip_attrs,
+            ref krate,
+            ref error,
+            ref diff,
+            ref merge,
+            ref try_from,
+            ref report,
+            ref compare,
+            ref undo,
+            serde_sparse: _,
+            ref field_names,
+            ref set_field,
+            ref env,
+            ref clap,
+            json: _,
+            bound: _,
+        } = *self;
+
+        // the generated struct and impls use the `bound` options, while impls naming the base struct keep its where-clause
+        let generics = &self.to_bounded_generics(true);
+        let partial_generics = &self.to_bounded_generics(false);
+
+        let (_, ty, wher) = partial_generics.split_for_impl();
+
+        let to_ident = if let Some(rename) = &rename {
+            rename.to_owned()
+        } else {
+            let title = format!("Partial{}", ident);
+            Ident::new(&title, ident.span())
+        };
+
+        // handle custom derive attr
+        if let Some(derive_paths) = derive {
+            let derive_paths =
+                TokenVec::new_with_vec_and_sep(derive_paths.to_vec(), Separator::Comma);
+            tokens.extend(quote! {
+                    #[derive(#derive_paths)]
+            });
+        }
+
+        // handle the clap derive
+        if clap.is_present() {
+            tokens.extend(quote! {
+                #[derive(clap::Args)]
+            });
+        }
+
+        // handle non-derive attrs
+        if !skip_attrs.is_present() {
+            for attr in attrs {
+                if !attr.path().is_ident("derive") {
+                    tokens.extend(quote!(#attr))
+                }
+            }
+        }
+
+        // handle additional attrs
+        let additional_attrs =
+            TokenVec::new_with_vec_and_sep(additional_attrs.to_vec(), Separator::Newline);
+        tokens.extend(quote! {
+            #additional_attrs
+        });
+
+        // derives of the generated struct that would include the marker need to skip it
+        let mut marker_skip_attrs = TokenStream::new();
+        if self.derives("Serialize") || self.derives("Deserialize") {
+            marker_skip_attrs.extend(quote!(#[serde(skip)]));
+        }
+        if clap.is_present() {
+            marker_skip_attrs.extend(quote!(#[arg(skip)]));
+        }
+
+        // write the enum, with its implementations
+        if let ast::Data::Enum(variants) = data.as_ref() {
+            let variant_fields: Vec<_> = variants.iter().flat_map(|v| v.dst_fields()).collect();
+            let marker =
+                PhantomMarker::new(partial_generics, &variant_fields, None, marker_skip_attrs);
+
+            let variant_tokens: Vec<_> = variants
+                .iter()
+                .map(|v| v.to_token_stream())
+                .chain(marker.iter().map(|m| m.to_variant()))
+                .collect();
+            let variant_tokens =
+                TokenVec::new_with_vec_and_sep(variant_tokens, Separator::CommaNewline);
+
+            tokens.extend(quote! {
+                #vis enum #to_ident #ty #wher {
+                    #variant_tokens
+                }
+            });
+
+            let impl_partial = ImplPartialEnum {
+                krate,
+                from_ident: ident,
+                to_ident: &to_ident,
+                generics,
+                variants: &variants,
+                marker: &marker,
+                is_base: true,
+                compare: compare.is_present(),
+                is_try: false,
+            };
+
+            // partials of a different variant that don't replace the value are rejected with an error
+            let impl_try_partial = ImplPartialEnum {
+                is_try: true,
+                ..impl_partial
+            };
+
+            let partial_impl_partial = ImplPartialEnum {
+                krate,
+                from_ident: &to_ident,
+                to_ident: &to_ident,
+                generics: partial_generics,
+                variants: &variants,
+                marker: &marker,
+                is_base: false,
+                compare: compare.is_present(),
+                is_try: false,
+            };
+
+            tokens.extend(quote! {
+                #impl_partial
+                #impl_try_partial
+                #partial_impl_partial
+            });
+
+            return;
+        }
+
+        let all_fields = data.as_ref().take_struct().expect("expected a struct");
+
+        let fields: Vec<_> = all_fields
+            .fields
+            .iter()
+            .copied()
+            .filter(|f| !f.omit.is_present())
+            .collect();
+
+        // fields of tuple structs are accessed by position, so the marker follows the generated fields
+        let marker = PhantomMarker::new(
+            partial_generics,
+            &fields,
+            all_fields.is_tuple().then_some(fields.len()),
+            marker_skip_attrs,
+        );
+
+        let field_tokens: Vec<_> = fields
+            .iter()
+            .map(|f| f.to_token_stream())
+            .chain(marker.iter().map(|m| m.to_token_stream()))
+            .collect();
+
+        // write the struct, keeping the style of the base struct
+        if all_fields.is_tuple() {
+            let field_tokens = TokenVec::new_with_vec_and_sep(field_tokens, Separator::Comma);
+
+            tokens.extend(quote! {
+                #vis struct #to_ident #ty (#field_tokens) #wher;
+            });
+        } else {
+            let field_tokens =
+                TokenVec::new_with_vec_and_sep(field_tokens, Separator::CommaNewline);
+
+            tokens.extend(quote! {
+                #vis struct #to_ident #ty #wher {
+                    #field_tokens
+                }
+            });
+        }
+
+        // create the impl
+        let impl_partial = ImplPartial {
+            krate,
+            from_ident: ident,
+            to_ident: &to_ident,
+            generics,
+            fields: &fields,
+            is_base: true,
+            compare: compare.is_present(),
+        };
+
+        // write the impl
+        tokens.extend(quote! {
+            #impl_partial
+        });
+
+        // create the partial => partial impl
+        let partial_impl_partial = ImplPartial {
+            krate,
+            from_ident: &to_ident,
+            to_ident: &to_ident,
+            generics: partial_generics,
+            fields: &fields,
+            is_base: false,
+            compare: compare.is_present(),
+        };
+
+        // write it
+        tokens.extend(quote! {
+            #partial_impl_partial
+        });
+
+        // create and write the try impl, if requested
+        if let Some(error) = error {
+            let impl_try_partial = ImplTryPartial {
+                krate,
+                from_ident: ident,
+                to_ident: &to_ident,
+                generics,
+                error,
+                fields: &fields,
+                compare: compare.is_present(),
+            };
+
+            tokens.extend(quote! {
+                #impl_try_partial
+            });
+        }
+
+        // create and write the diff impl, if requested
+        if diff.is_present() {
+            let impl_diff = ImplDiff {
+                krate,
+                from_ident: ident,
+                to_ident: &to_ident,
+                generics,
+                fields: &fields,
+                marker: &marker,
+            };
+
+            tokens.extend(quote! {
+                #impl_diff
+            });
+        }
+
+        // create and write the merge impl, if requested
+        if merge.is_present() {
+            let impl_merge = ImplMerge {
+                krate,
+                to_ident: &to_ident,
+                generics: partial_generics,
+                fields: &fields,
+                marker: &marker,
+            };
+
+            tokens.extend(quote! {
+                #impl_merge
+            });
+        }
+
+        // create and write the try from impl, if requested
+        if try_from.is_present() {
+            let impl_try_from = ImplTryFrom {
+                krate,
+                from_ident: ident,
+                to_ident: &to_ident,
+                generics,
+                fields: &all_fields.fields,
+            };
+
+            tokens.extend(quote! {
+                #impl_try_from
+            });
+        }
+
+        // create and write the report impl, if requested
+        if report.is_present() {
+            let report_ident = Ident::new(&format!("{}Fields", ident), ident.span());
+
+            let impl_report = ImplReport {
+                krate,
+                vis,
+                from_ident: ident,
+                report_ident: &report_ident,
+                generics,
+                fields: &fields,
+                compare: compare.is_present(),
+            };
+
+            tokens.extend(quote! {
+                #impl_report
+            });
+        }
+
+        // create and write the undo impl, if requested
+        if undo.is_present() {
+            let impl_undo = ImplUndo {
+                krate,
+                from_ident: ident,
+                to_ident: &to_ident,
+                generics,
+                fields: &fields,
+                marker: &marker,
+                compare: compare.is_present(),
+            };
+
+            tokens.extend(quote! {
+                #impl_undo
+            });
+        }
+
+        // create and write the field names impl, if requested
+        if field_names.is_present() {
+            let impl_field_names = ImplFieldNames {
+                krate,
+                to_ident: &to_ident,
+                generics: partial_generics,
+                fields: &fields,
+            };
+
+            tokens.extend(quote! {
+                #impl_field_names
+            });
+        }
+
+        // create and write the set field impl, if requested
+        if set_field.is_present() {
+            let impl_set_field = ImplSetField {
+                krate,
+                to_ident: &to_ident,
+                generics: partial_generics,
+                fields: &fields,
+            };
+
+            tokens.extend(quote! {
+                #impl_set_field
+            });
+        }
+
+        // create and write the from env impl, if requested
+        if env.is_present() {
+            let impl_from_env = ImplFromEnv {
+                krate,
+                to_ident: &to_ident,
+                generics: partial_generics,
+                fields: &fields,
+                marker: &marker,
+            };
+
+            tokens.extend(quote! {
+                #impl_from_env
+            });
+        }
+    }
+}
@@ -1,189 +1,842 @@
-use darling::{util::Flag, FromField, Result};
-use quote::{quote, ToTokens};
-use syn::{parse_quote, Ident, Type, Visibility};
-
-#[derive(Debug, FromField)]
-#[darling(attributes(partially), forward_attrs, and_then = FieldReceiver::validate)]
-pub struct FieldReceiver {
-    /// Get the ident of the field. For fields in tuple or newtype structs or
-    /// enum bodies, this can be [`None`].
-    pub ident: Option<Ident>,
-
-    /// Get the visibility of the field.
-    pub vis: Visibility,
-
-    /// Get the attributes of the field.
-    pub attrs: Vec<syn::Attribute>,
-
-    /// This magic field name pulls the type from the input.
-    pub ty: Type,
-
-    /// An optional identifer to use for the generated field.
-    ///
-    /// Note: By default, `Partial` + [`Self::ident`] is used.  
-    pub rename: Option<Ident>,
-
-    /// A flag indicating that the given field should be omitted from generation.
-    ///
-    /// Note: This will create a generated struct that is missing the [`Self::ident`] field.
-    pub omit: Flag,
-
-    /// A flag indicating that the given field should not be "partial-ized" and instead
-    /// should be directly forwarded to the child.
-    ///
-    /// Note: This means that [`Self::ty`] will be used for the generated field, rather than [`Option<Self::ty>`].
-    pub transparent: Flag,
-
-    /// An optional type override to use for the generated field.
-    ///
-    /// Note: If specified, the given [`Type`] will be used verbatim, not wrapped in an [`Option`].
-    /// Note: By default, [`Option<Self::ty>`] is used.
-    pub as_type: Option<Type>,
-}
-
-impl FieldReceiver {
-    fn validate(self) -> Result<Self> {
-        let mut acc = darling::Error::accumulator();
-
-        if self.ident.is_none() {
-            acc.push(darling::Error::custom(
-                "cannot use rename on an unnamed field",
-            ))
-        }
-
-        if self.omit.is_present()
-            && (self.rename.is_some() || self.transparent.is_present() || self.as_type.is_some())
-        {
-            acc.push(darling::Error::custom(
-                "cannot use omit with any other options",
-            ));
-        }
-
-        if self.transparent.is_present() && self.as_type.is_some() {
-            acc.push(darling::Error::custom(
-                "cannot use both transparent and as_type",
-            ));
-        }
-
-        acc.finish_with(self)
-    }
-}
-
-impl ToTokens for FieldReceiver {
-    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
-        if self.omit.is_present() {
-            return;
-        }
-
-        // this is enforced with a better error by [`FieldReceiver::validate`].
-        let src_name = self.ident.as_ref().expect("expected a named field");
-
-        let dst_name = if let Some(name) = &self.rename {
-            name
-        } else {
-            src_name
-        };
-
-        let src_type = &self.ty;
-        let dst_type = if self.transparent.is_present() {
-            src_type.to_owned()
-        } else if let Some(ty) = &self.as_type {
-            ty.to_owned()
-        } else {
-            let ty: Type = parse_quote! {
-                Option<#src_type>
-            };
-
-            ty
-        };
-
-        let vis = &self.vis;
-        let forwarded_attrs = &self.attrs;
-
-        for attr in forwarded_attrs {
-            tokens.extend(quote! {
-                #attr
-            })
-        }
-
-        tokens.extend(quote! {
-            #vis #dst_name: #dst_type
-        })
-    }
-}
-
-#[cfg(test)]
-mod test {
-    use darling::util::Flag;
-    use proc_macro2::Span;
-    use quote::quote;
-    use syn::Ident;
-
-    use super::FieldReceiver;
-
-    fn make_dummy() -> FieldReceiver {
-        FieldReceiver {
-            ident: Some(Ident::new("Dummy", Span::call_site())),
-            vis: syn::Visibility::Public(syn::token::Pub::default()),
-            attrs: Vec::new(),
-            ty: syn::Type::Verbatim(quote!(DummyField)),
-            rename: None,
-            omit: Flag::default(),
-            transparent: Flag::default(),
-            as_type: None,
-        }
-    }
-
-    #[test]
-    fn invalidates_no_ident() {
-        let mut instance = make_dummy();
-        instance.ident = None;
-
-        if let Ok(e) = instance.validate() {
-            println!("{:?}", e)
-        }
-    }
-
-    #[test]
-    fn invalidate_omit_rename() {
-        let mut instance = make_dummy();
-        instance.omit = Flag::present();
-        instance.rename = Some(Ident::new("Renamed", Span::call_site()));
-
-        assert!(instance.validate().is_err())
-    }
-
-    #[test]
-    fn invalidate_omit_transparent() {
-        let mut instance = make_dummy();
-        instance.omit = Flag::present();
-        instance.transparent = Flag::present();
-
-        assert!(instance.validate().is_err())
-    }
-
-    #[test]
-    fn invalidate_omit_as_type() {
-        let mut instance = make_dummy();
-        instance.omit = Flag::present();
-        instance.as_type = Some(syn::Type::Verbatim(quote!(NewDummyField)));
-
-        assert!(instance.validate().is_err())
-    }
-
-    #[test]
-    fn invalidate_transparent_as_type() {
-        let mut instance = make_dummy();
-        instance.transparent = Flag::present();
-        instance.as_type = Some(syn::Type::Verbatim(quote!(NewDummyField)));
-
-        assert!(instance.validate().is_err())
-    }
-
-    #[test]
-    fn validate() {
-        let instance = make_dummy();
-
-        assert!(instance.validate().is_ok());
-    }
-}
+use darling::{util::Flag, FromField, FromMeta, Result};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, ToTokens};
+use syn::{
+    parse_quote, Expr, GenericArgument, Ident, Index, Member, Path, PathArguments, Type,
+    Visibility, WherePredicate,
+};
+
+/// How a `transparent` field is applied to the base struct.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Transparent {
+    /// The generated field value is applied when it's [`Some`], like any other field.
+    ///
+    /// Note: Only used for fields with an [`Option`] type, when no mode is given.
+    WhenSome,
+    /// The generated field value always overwrites the base struct field.
+    Always,
+    /// The generated field value is never applied to the base struct field.
+    Never,
+}
+
+impl FromMeta for Transparent {
+    fn from_word() -> Result<Self> {
+        // this is resolved to `Always` for fields that aren't an `Option`, by [`FieldReceiver::validate`]
+        Ok(Self::WhenSome)
+    }
+
+    fn from_string(value: &str) -> Result<Self> {
+        match value {
+            "always" => Ok(Self::Always),
+            "never" => Ok(Self::Never),
+            _ => Err(darling::Error::unknown_value(value)),
+        }
+    }
+}
+
+#[derive(Debug, FromField)]
+#[darling(attributes(partially), forward_attrs, and_then = FieldReceiver::validate)]
+pub struct FieldReceiver {
+    /// Get the ident of the field. For fields in tuple or newtype structs or
+    /// enum bodies, this can be [`None`].
+    pub ident: Option<Ident>,
+
+    /// Get the visibility of the field.
+    pub vis: Visibility,
+
+    /// Get the attributes of the field.
+    pub attrs: Vec<syn::Attribute>,
+
+    /// This magic field name pulls the type from the input.
+    pub ty: Type,
+
+    /// An optional identifer to use for the generated field.
+    ///
+    /// Note: By default, `Partial` + [`Self::ident`] is used.  
+    pub rename: Option<Ident>,
+
+    /// A flag indicating that the given field should be omitted from generation.
+    ///
+    /// Note: This will create a generated struct that is missing the [`Self::ident`] field.
+    pub omit: Flag,
+
+    /// A flag indicating that the given field should not be "partial-ized" and instead
+    /// should be directly forwarded to the child.
+    ///
+    /// Note: This means that [`Self::ty`] will be used for the generated field, rather than [`Option<Self::ty>`].
+    ///
+    /// Note: By default, the generated field value is applied when it's [`Some`] for [`Option`] fields, and always
+    /// overwrites the base struct field otherwise. With `transparent = "always"` it always overwrites the base struct
+    /// field, and with `transparent = "never"`, it's never applied.
+    pub transparent: Option<Transparent>,
+
+    /// An optional type override to use for the generated field.
+    ///
+    /// Note: If specified, the given [`Type`] will be used verbatim, not wrapped in an [`Option`].
+    /// Note: By default, [`Option<Self::ty>`] is used.
+    pub as_type: Option<Type>,
+
+    /// A flag indicating that the given field implements `Partial` itself, and should be
+    /// "partial-ized" using that implementation rather than being wrapped in an [`Option`].
+    ///
+    /// Note: This means that [`Option<<Self::ty as Partial>::Item>`] will be used for the generated field,
+    /// and that applying the field will recurse into [`Self::ty`]'s `apply_some`.
+    pub nested: Flag,
+
+    /// A flag indicating that the generated field should be converted to [`Self::ty`] using `TryInto`, rather than `Into`.
+    ///
+    /// Note: This is only used by the `TryPartial` implementation, which requires that the conversion error can be
+    /// converted into the container's `error` type.
+    pub try_into: Flag,
+
+    /// An optional path to a function used to validate the field value before it is applied.
+    ///
+    /// Note: The function must have the signature `fn(&BaseType) -> Result<(), E>`, where `E` can be converted into
+    /// the container's `error` type. This is only used by the `TryPartial` implementation.
+    pub validate: Option<Path>,
+
+    /// An optional expression used to fill the `omit`-ted field, when converting the generated struct into the base struct.
+    ///
+    /// Note: By default, `Default::default()` is used.
+    pub default: Option<Expr>,
+
+    /// A flag indicating that the given [`Option`] field should use `Patch` for the generated field, rather than
+    /// being wrapped in another [`Option`].
+    ///
+    /// Note: This means that `Patch<T>` will be used for the generated field, where [`Self::ty`] is [`Option<T>`].
+    pub patch: Flag,
+
+    /// An optional list of where-clause predicates needed by the generated code for this field, such as `T: Clone`.
+    ///
+    /// Note: These extend the where-clause of the generated struct and each generated implementation.
+    pub bound: Option<Vec<WherePredicate>>,
+
+    /// The position of the field in the base struct, used to access fields of tuple structs.
+    ///
+    /// Note: This isn't parsed from the field, rather it's populated by `DeriveReceiver`.
+    #[darling(skip)]
+    pub index: usize,
+
+    /// The position of the field in the generated struct, which differs from [`Self::index`] when preceding fields
+    /// are `omit`-ted.
+    ///
+    /// Note: This isn't parsed from the field, rather it's populated by `DeriveReceiver`.
+    #[darling(skip)]
+    pub dst_index: usize,
+
+    /// The path to the `partially` crate.
+    ///
+    /// Note: This isn't parsed from the field, rather it's populated from the container by `DeriveReceiver`.
+    #[darling(skip)]
+    pub krate: Option<Path>,
+
+    /// Whether the generated field should skip serializing absent values, and deserialize missing values as absent.
+    ///
+    /// Note: This isn't parsed from the field, rather it's populated from the container's `serde_sparse` option by `DeriveReceiver`.
+    #[darling(skip)]
+    pub serde_sparse: bool,
+
+    /// Whether the generated struct derives `Serialize`, such that `patch` fields skip serializing missing values.
+    ///
+    /// Note: This isn't parsed from the field, rather it's populated from the container's `derive` option by `DeriveReceiver`.
+    #[darling(skip)]
+    pub serde_serialize: bool,
+
+    /// Whether the generated struct derives `Deserialize`, such that `patch` fields deserialize missing values as missing.
+    ///
+    /// Note: This isn't parsed from the field, rather it's populated from the container's `derive` option by `DeriveReceiver`.
+    #[darling(skip)]
+    pub serde_deserialize: bool,
+
+    /// Whether the generated field should be a `clap` argument.
+    ///
+    /// Note: This isn't parsed from the field, rather it's populated from the container's `clap` option by `DeriveReceiver`.
+    #[darling(skip)]
+    pub clap: bool,
+
+    /// Whether the generated field should be serialized such that the `json` module recognizes it.
+    ///
+    /// Note: This isn't parsed from the field, rather it's populated from the container's `json` option by `DeriveReceiver`.
+    #[darling(skip)]
+    pub json: bool,
+}
+
+impl FieldReceiver {
+    fn validate(mut self) -> Result<Self> {
+        let mut acc = darling::Error::accumulator();
+
+        // fields that aren't an `Option` can't be checked for `Some`, so they're always applied
+        if self.transparent == Some(Transparent::WhenSome) && option_type(&self.ty).is_none() {
+            self.transparent = Some(Transparent::Always);
+        }
+
+        if self.ident.is_none() && self.rename.is_some() {
+            acc.push(darling::Error::custom(
+                "cannot use rename on an unnamed field",
+            ))
+        }
+
+        if self.omit.is_present()
+            && (self.rename.is_some()
+                || self.transparent.is_some()
+                || self.as_type.is_some()
+                || self.nested.is_present()
+                || self.try_into.is_present()
+                || self.validate.is_some()
+                || self.patch.is_present())
+        {
+            acc.push(darling::Error::custom(
+                "cannot use omit with any other options, except default",
+            ));
+        }
+
+        if self.default.is_some() && !self.omit.is_present() {
+            acc.push(darling::Error::custom("cannot use default without omit"));
+        }
+
+        if self.transparent.is_some() && self.as_type.is_some() {
+            acc.push(darling::Error::custom(
+                "cannot use both transparent and as_type",
+            ));
+        }
+
+        if self.nested.is_present() && (self.transparent.is_some() || self.as_type.is_some()) {
+            acc.push(darling::Error::custom(
+                "cannot use nested with transparent or as_type",
+            ));
+        }
+
+        if self.nested.is_present() && (self.try_into.is_present() || self.validate.is_some()) {
+            acc.push(darling::Error::custom(
+                "cannot use nested with try_into or validate",
+            ));
+        }
+
+        if self.transparent.is_some() && self.is_fallible() {
+            acc.push(darling::Error::custom(
+                "cannot use transparent with try_into or validate",
+            ));
+        }
+
+        if self.patch.is_present()
+            && (self.transparent.is_some()
+                || self.as_type.is_some()
+                || self.nested.is_present()
+                || self.is_fallible())
+        {
+            acc.push(darling::Error::custom(
+                "cannot use patch with transparent, as_type, nested, try_into or validate",
+            ));
+        }
+
+        if self.patch.is_present() && self.patch_type().is_none() {
+            acc.push(
+                darling::Error::custom("cannot use patch on a field that is not an Option")
+                    .with_span(&self.ty),
+            );
+        }
+
+        acc.finish_with(self)
+    }
+
+    /// Gets the path to the `partially` crate.
+    fn krate(&self) -> Path {
+        // parse the crate config, or use `partially` for the crate path
+        self.krate
+            .clone()
+            .unwrap_or_else(|| parse_quote!(partially))
+    }
+
+    /// Gets `T`, where [`Self::ty`] is [`Option<T>`], for fields using the `patch` option.
+    fn patch_type(&self) -> Option<&Type> {
+        if !self.patch.is_present() {
+            return None;
+        }
+
+        option_type(&self.ty)
+    }
+
+    /// Generates a `#[serde(..)]` attribute that skips serializing absent values of the generated field, and
+    /// deserializes missing values as absent.
+    ///
+    /// Note: Only fields using the `patch` option, or whose generated type is an [`Option`], have absent values.
+    fn to_sparse_attr(&self) -> Option<TokenStream> {
+        let is_absent = if self.patch.is_present() {
+            self.to_serde_path("Patch::is_missing")
+        } else if self.is_dst_option() {
+            "Option::is_none".to_owned()
+        } else {
+            return None;
+        };
+
+        Some(quote! {
+            #[serde(default, skip_serializing_if = #is_absent)]
+        })
+    }
+
+    /// Generates a `#[serde(..)]` attribute for a field using the `patch` option, that deserializes a missing value as
+    /// `Patch::Missing` (rather than `Patch::Null`), and skips serializing it, for the traits the generated struct derives.
+    fn to_patch_attr(&self) -> Option<TokenStream> {
+        let is_missing = self.to_serde_path("Patch::is_missing");
+        let args = [
+            self.serde_deserialize.then(|| quote!(default)),
+            self.serde_serialize
+                .then(|| quote!(skip_serializing_if = #is_missing)),
+        ];
+        let args: Vec<_> = args.into_iter().flatten().collect();
+
+        (!args.is_empty()).then(|| quote!(#[serde(#(#args),*)]))
+    }
+
+    /// Gets the string `serde` parses as the path `path` of the `partially` crate.
+    fn to_serde_path(&self, path: &str) -> String {
+        let krate = self.krate();
+
+        // serde parses the path from a string, so we remove the spaces `quote!` adds between tokens
+        format!("{}::{}", quote!(#krate).to_string().replace(' ', ""), path)
+    }
+
+    /// Generates an expression that converts a generated field value (given by `value`) into an [`Option`].
+    ///
+    /// Note: For fields using the `patch` option, this is an [`Option<Option<T>>`]. Otherwise, `value` is used as is.
+    pub fn to_option(&self, value: TokenStream) -> TokenStream {
+        if self.patch.is_present() {
+            let krate = self.krate();
+
+            quote!(#krate::Patch::into_option(#value))
+        } else {
+            value
+        }
+    }
+
+    /// Generates an expression that converts an [`Option`] (given by `value`) into a generated field value.
+    ///
+    /// Note: For fields using the `patch` option, `value` must be an [`Option<Option<T>>`]. Otherwise, `value` is used as is.
+    pub fn to_field(&self, value: TokenStream) -> TokenStream {
+        if self.patch.is_present() {
+            let krate = self.krate();
+
+            quote!(#krate::Patch::from(#value))
+        } else {
+            value
+        }
+    }
+
+    /// Generates an expression that checks if a generated field value (given by `value`) is present, without moving it.
+    ///
+    /// Note: `transparent` fields are always present when they use `transparent = "always"`, and never present when
+    /// they use `transparent = "never"`.
+    pub fn to_is_some(&self, value: TokenStream) -> TokenStream {
+        if let Some(transparent @ (Transparent::Always | Transparent::Never)) = self.transparent {
+            let is_some = transparent == Transparent::Always;
+
+            quote!(#is_some)
+        } else if self.patch.is_present() {
+            quote!(!#value.is_missing())
+        } else {
+            quote!(#value.is_some())
+        }
+    }
+
+    /// Generates statements that bind a generated field value (given by `value`) to [`Self::dst_ident`], followed by
+    /// `body`, when the value is present.
+    ///
+    /// Note: `transparent` fields are always present when they use `transparent = "always"`, and never present when
+    /// they use `transparent = "never"` - in which case nothing is generated.
+    pub fn to_if_some(&self, value: TokenStream, body: TokenStream) -> TokenStream {
+        let to_ident = self.dst_ident();
+
+        match self.transparent {
+            Some(Transparent::Always) => quote! {
+                let #to_ident = #value;
+                #body
+            },
+            Some(Transparent::Never) => quote!(),
+            Some(Transparent::WhenSome) | None => {
+                let value = self.to_option(value);
+
+                quote! {
+                    if let Some(#to_ident) = #value {
+                        #body
+                    }
+                }
+            }
+        }
+    }
+
+    /// Gets the member used to access the field in the base struct.
+    ///
+    /// Note: For fields of tuple structs, this is the index of the field.
+    pub fn src_member(&self) -> Member {
+        match &self.ident {
+            Some(ident) => Member::Named(ident.to_owned()),
+            None => Member::Unnamed(Index::from(self.index)),
+        }
+    }
+
+    /// Gets the name of the field in the base struct.
+    ///
+    /// Note: For fields of tuple structs, this is the index of the field.
+    pub fn src_name(&self) -> String {
+        match &self.ident {
+            Some(ident) => ident.to_string(),
+            None => self.index.to_string(),
+        }
+    }
+
+    /// Gets the type of the field in the generated struct.
+    pub fn dst_type(&self) -> Type {
+        let src_type = &self.ty;
+
+        if self.transparent.is_some() {
+            src_type.to_owned()
+        } else if let Some(ty) = &self.as_type {
+            ty.to_owned()
+        } else if self.nested.is_present() {
+            let krate = self.krate();
+
+            parse_quote! {
+                Option<<#src_type as #krate::Partial>::Item>
+            }
+        } else if let Some(patch_type) = self.patch_type() {
+            let krate = self.krate();
+
+            parse_quote! {
+                #krate::Patch<#patch_type>
+            }
+        } else {
+            parse_quote! {
+                Option<#src_type>
+            }
+        }
+    }
+
+    /// Gets whether the field in the generated struct is an [`Option`].
+    pub fn is_dst_option(&self) -> bool {
+        option_type(&self.dst_type()).is_some()
+    }
+
+    /// Gets whether applying the field may fail, due to the `try_into` or `validate` options.
+    pub fn is_fallible(&self) -> bool {
+        self.try_into.is_present() || self.validate.is_some()
+    }
+
+    /// Gets the identifier used to bind values of the field in generated code.
+    ///
+    /// Note: For named fields this is the identifier of the field in the generated struct, and for fields of tuple
+    /// structs it's `field_{index}`.
+    pub fn dst_ident(&self) -> Ident {
+        match (&self.rename, &self.ident) {
+            (Some(ident), _) | (None, Some(ident)) => ident.to_owned(),
+            (None, None) => format_ident!("field_{}", self.dst_index),
+        }
+    }
+
+    /// Gets the member used to access the field in the generated struct.
+    ///
+    /// Note: For fields of tuple structs, this is the index of the field.
+    pub fn dst_member(&self) -> Member {
+        match (&self.rename, &self.ident) {
+            (Some(ident), _) | (None, Some(ident)) => Member::Named(ident.to_owned()),
+            (None, None) => Member::Unnamed(Index::from(self.dst_index)),
+        }
+    }
+
+    /// Gets the name of the field in the generated struct.
+    ///
+    /// Note: For fields of tuple structs, this is the index of the field.
+    pub fn dst_name(&self) -> String {
+        match (&self.rename, &self.ident) {
+            (Some(ident), _) | (None, Some(ident)) => ident.to_string(),
+            (None, None) => self.dst_index.to_string(),
+        }
+    }
+
+    /// Generates statements that apply a generated field value (bound to [`Self::dst_ident`]) to the base
+    /// struct field (given by the place expression `target`), followed by `on_applied`.
+    ///
+    /// Note: For fallible fields (see [`Self::is_fallible`]) values that fail conversion or validation are skipped,
+    /// and `on_applied` is not run.
+    ///
+    /// Note: When `compare` is `true`, see [`Self::to_assignment`].
+    pub fn to_applicator(
+        &self,
+        target: TokenStream,
+        compare: bool,
+        on_applied: TokenStream,
+    ) -> TokenStream {
+        let to_ident = self.dst_ident();
+
+        if !self.is_fallible() && !compare {
+            return quote! {
+                #target = #to_ident.into();
+                #on_applied
+            };
+        }
+
+        self.to_converted(self.to_assignment(target, compare, on_applied))
+    }
+
+    /// Generates statements that convert a generated field value (bound to [`Self::dst_ident`]) into the base
+    /// struct field type, followed by `body` (where [`Self::dst_ident`] is bound to the converted value).
+    ///
+    /// Note: For fallible fields (see [`Self::is_fallible`]) values that fail conversion or validation are skipped,
+    /// and `body` is not run.
+    pub fn to_converted(&self, mut body: TokenStream) -> TokenStream {
+        let to_ident = self.dst_ident();
+        let src_type = &self.ty;
+
+        // values that fail validation are skipped
+        if let Some(validate) = &self.validate {
+            body = quote! {
+                if #validate(&#to_ident).is_ok() {
+                    #body
+                }
+            };
+        }
+
+        // values that fail conversion are skipped
+        if self.try_into.is_present() {
+            quote! {
+                if let Ok(#to_ident) = core::convert::TryInto::<#src_type>::try_into(#to_ident) {
+                    #body
+                }
+            }
+        } else {
+            quote! {
+                let #to_ident: #src_type = #to_ident.into();
+                #body
+            }
+        }
+    }
+
+    /// Generates statements that assign an already converted value (bound to [`Self::dst_ident`]) to the base
+    /// struct field (given by the place expression `target`), followed by `on_applied`.
+    ///
+    /// Note: When `compare` is `true`, the value is only assigned (and `on_applied` only run) when it is
+    /// not equal to the current value.
+    pub fn to_assignment(
+        &self,
+        target: TokenStream,
+        compare: bool,
+        on_applied: TokenStream,
+    ) -> TokenStream {
+        let to_ident = self.dst_ident();
+
+        if compare {
+            quote! {
+                if #target != #to_ident {
+                    #target = #to_ident;
+                    #on_applied
+                }
+            }
+        } else {
+            quote! {
+                #target = #to_ident;
+                #on_applied
+            }
+        }
+    }
+}
+
+/// Gets `T`, where `ty` is [`Option<T>`].
+fn option_type(ty: &Type) -> Option<&Type> {
+    let Type::Path(ty) = ty else {
+        return None;
+    };
+
+    let segment = ty.path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+
+    match args.args.first() {
+        Some(GenericArgument::Type(ty)) if args.args.len() == 1 => Some(ty),
+        _ => None,
+    }
+}
+
+impl ToTokens for FieldReceiver {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        if self.omit.is_present() {
+            return;
+        }
+
+        let dst_type = self.dst_type();
+
+        // fields of tuple structs are unnamed
+        let dst_name = match self.dst_member() {
+            Member::Named(ident) => quote!(#ident:),
+            Member::Unnamed(_) => quote!(),
+        };
+
+        let vis = &self.vis;
+        let forwarded_attrs = &self.attrs;
+
+        for attr in forwarded_attrs {
+            tokens.extend(quote! {
+                #attr
+            })
+        }
+
+        if self.serde_sparse {
+            tokens.extend(self.to_sparse_attr());
+        } else if self.patch.is_present() {
+            tokens.extend(self.to_patch_attr());
+        }
+
+        // nested partials are marked, so the `json` module emits operations for their members rather than the whole value
+        if self.json && self.nested.is_present() {
+            let serialize_with = self.to_serde_path("__private::serialize_nested");
+
+            tokens.extend(quote!(#[serde(serialize_with = #serialize_with)]));
+        }
+
+        // patches are marked, so the `json` module can tell a `Patch::Null` value from an absent value
+        if self.json && self.patch.is_present() {
+            let serialize_with = self.to_serde_path("__private::serialize_patch");
+
+            tokens.extend(quote!(#[serde(serialize_with = #serialize_with)]));
+        }
+
+        // nested partials are `clap::Args` themselves, so their arguments are flattened into ours
+        if self.clap && self.nested.is_present() {
+            tokens.extend(quote!(#[command(flatten)]));
+        } else if self.clap {
+            tokens.extend(quote!(#[arg(long)]));
+        }
+
+        tokens.extend(quote! {
+            #vis #dst_name #dst_type
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use darling::util::Flag;
+    use proc_macro2::Span;
+    use quote::quote;
+    use syn::{parse_quote, Ident};
+
+    use super::{FieldReceiver, Transparent};
+
+    fn make_dummy() -> FieldReceiver {
+        FieldReceiver {
+            ident: Some(Ident::new("Dummy", Span::call_site())),
+            vis: syn::Visibility::Public(syn::token::Pub::default()),
+            attrs: Vec::new(),
+            ty: syn::Type::Verbatim(quote!(DummyField)),
+            rename: None,
+            omit: Flag::default(),
+            transparent: None,
+            as_type: None,
+            nested: Flag::default(),
+            try_into: Flag::default(),
+            validate: None,
+            default: None,
+            patch: Flag::default(),
+            bound: None,
+            serde_sparse: false,
+            serde_serialize: false,
+            serde_deserialize: false,
+            clap: false,
+            json: false,
+            index: 0,
+            dst_index: 0,
+            krate: None,
+        }
+    }
+
+    #[test]
+    fn invalidates_no_ident() {
+        let mut instance = make_dummy();
+        instance.ident = None;
+        instance.rename = Some(Ident::new("Renamed", Span::call_site()));
+
+        assert!(instance.validate().is_err())
+    }
+
+    #[test]
+    fn validates_no_ident() {
+        let mut instance = make_dummy();
+        instance.ident = None;
+        instance.transparent = Some(Transparent::Always);
+
+        assert!(instance.validate().is_ok())
+    }
+
+    #[test]
+    fn resolves_transparent_mode() {
+        let mut instance = make_dummy();
+        instance.transparent = Some(Transparent::WhenSome);
+
+        assert_eq!(
+            instance.validate().unwrap().transparent,
+            Some(Transparent::Always)
+        );
+
+        let mut instance = make_dummy();
+        instance.ty = parse_quote!(Option<DummyField>);
+        instance.transparent = Some(Transparent::WhenSome);
+
+        assert_eq!(
+            instance.validate().unwrap().transparent,
+            Some(Transparent::WhenSome)
+        );
+    }
+
+    #[test]
+    fn invalidate_omit_rename() {
+        let mut instance = make_dummy();
+        instance.omit = Flag::present();
+        instance.rename = Some(Ident::new("Renamed", Span::call_site()));
+
+        assert!(instance.validate().is_err())
+    }
+
+    #[test]
+    fn invalidate_omit_transparent() {
+        let mut instance = make_dummy();
+        instance.omit = Flag::present();
+        instance.transparent = Some(Transparent::Always);
+
+        assert!(instance.validate().is_err())
+    }
+
+    #[test]
+    fn invalidate_omit_as_type() {
+        let mut instance = make_dummy();
+        instance.omit = Flag::present();
+        instance.as_type = Some(syn::Type::Verbatim(quote!(NewDummyField)));
+
+        assert!(instance.validate().is_err())
+    }
+
+    #[test]
+    fn invalidate_transparent_as_type() {
+        let mut instance = make_dummy();
+        instance.transparent = Some(Transparent::Always);
+        instance.as_type = Some(syn::Type::Verbatim(quote!(NewDummyField)));
+
+        assert!(instance.validate().is_err())
+    }
+
+    #[test]
+    fn invalidate_omit_nested() {
+        let mut instance = make_dummy();
+        instance.omit = Flag::present();
+        instance.nested = Flag::present();
+
+        assert!(instance.validate().is_err())
+    }
+
+    #[test]
+    fn invalidate_nested_transparent() {
+        let mut instance = make_dummy();
+        instance.nested = Flag::present();
+        instance.transparent = Some(Transparent::Always);
+
+        assert!(instance.validate().is_err())
+    }
+
+    #[test]
+    fn invalidate_nested_as_type() {
+        let mut instance = make_dummy();
+        instance.nested = Flag::present();
+        instance.as_type = Some(syn::Type::Verbatim(quote!(NewDummyField)));
+
+        assert!(instance.validate().is_err())
+    }
+
+    #[test]
+    fn invalidate_omit_try_into() {
+        let mut instance = make_dummy();
+        instance.omit = Flag::present();
+        instance.try_into = Flag::present();
+
+        assert!(instance.validate().is_err())
+    }
+
+    #[test]
+    fn invalidate_omit_validate() {
+        let mut instance = make_dummy();
+        instance.omit = Flag::present();
+        instance.validate = Some(parse_quote!(validate_dummy));
+
+        assert!(instance.validate().is_err())
+    }
+
+    #[test]
+    fn invalidate_nested_try_into() {
+        let mut instance = make_dummy();
+        instance.nested = Flag::present();
+        instance.try_into = Flag::present();
+
+        assert!(instance.validate().is_err())
+    }
+
+    #[test]
+    fn invalidate_default_without_omit() {
+        let mut instance = make_dummy();
+        instance.default = Some(parse_quote!(dummy_default()));
+
+        assert!(instance.validate().is_err())
+    }
+
+    #[test]
+    fn validate_omit_default() {
+        let mut instance = make_dummy();
+        instance.omit = Flag::present();
+        instance.default = Some(parse_quote!(dummy_default()));
+
+        assert!(instance.validate().is_ok())
+    }
+
+    #[test]
+    fn validate() {
+        let instance = make_dummy();
+
+        assert!(instance.validate().is_ok());
+    }
+
+    #[test]
+    fn invalidate_patch_nested() {
+        let mut instance = make_dummy();
+        instance.ty = parse_quote!(Option<DummyField>);
+        instance.patch = Flag::present();
+        instance.nested = Flag::present();
+
+        assert!(instance.validate().is_err())
+    }
+
+    #[test]
+    fn invalidate_patch_not_option() {
+        let mut instance = make_dummy();
+        instance.patch = Flag::present();
+
+        assert!(instance.validate().is_err())
+    }
+
+    #[test]
+    fn validate_patch_option() {
+        let mut instance = make_dummy();
+        instance.ty = parse_quote!(core::option::Option<DummyField>);
+        instance.patch = Flag::present();
+
+        assert!(instance.validate().is_ok())
+    }
+
+    #[test]
+    fn invalidate_transparent_try_into() {
+        let mut instance = make_dummy();
+        instance.transparent = Some(Transparent::Never);
+        instance.try_into = Flag::present();
+
+        assert!(instance.validate().is_err())
+    }
+}
@@ -0,0 +1,94 @@
+use quote::{quote, ToTokens};
+use syn::{parse_quote, Generics, Ident, Path};
+
+use super::{
+    field_receiver::{FieldReceiver, Transparent},
+    phantom_marker::PhantomMarker,
+    token_vec::{Separator, TokenVec},
+};
+
+pub struct ImplDiff<'a> {
+    pub krate: &'a Option<Path>,
+    pub generics: &'a Generics,
+    pub from_ident: &'a Ident,
+    pub to_ident: &'a Ident,
+
+    /// Note: assumed to already be filtered (such that `omit`-ted entries are removed)
+    pub fields: &'a Vec<&'a FieldReceiver>,
+
+    /// The `PhantomData` marker of the generated struct, if any, which is filled when building it.
+    pub marker: &'a Option<PhantomMarker>,
+}
+
+impl<'a> ToTokens for ImplDiff<'a> {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let Self {
+            krate,
+            from_ident,
+            to_ident,
+            generics,
+            fields,
+            marker,
+        } = self;
+
+        let (imp, ty, wher) = generics.split_for_impl();
+
+        // parse the crate config, or use `partially` for the crate path
+        let krate = if let Some(krate) = krate {
+            krate.to_owned()
+        } else {
+            parse_quote!(partially)
+        };
+
+        let field_diffs = fields
+            .iter()
+            .map(|f| {
+                let from_member = f.src_member();
+                let to_member = f.dst_member();
+
+                // transparent fields that aren't checked for `Some` hold the new value as is
+                if matches!(
+                    f.transparent,
+                    Some(Transparent::Always | Transparent::Never)
+                ) {
+                    return quote! {
+                        #to_member: other.#from_member.clone()
+                    };
+                }
+
+                let value = if f.nested.is_present() {
+                    quote!(Some(#krate::Diff::diff(&self.#from_member, &other.#from_member)))
+                } else if f.transparent.is_some() {
+                    // transparent `Option` fields are already an `Option`
+                    quote!(other.#from_member.clone())
+                } else {
+                    quote!(Some(other.#from_member.clone()))
+                };
+
+                let diff = f.to_field(quote! {
+                    if self.#from_member != other.#from_member {
+                        #value
+                    } else {
+                        None
+                    }
+                });
+
+                quote! {
+                    #to_member: #diff
+                }
+            })
+            .chain(marker.iter().map(|m| m.to_value()))
+            .collect();
+        let field_diffs = TokenVec::new_with_vec_and_sep(field_diffs, Separator::CommaNewline);
+
+        tokens.extend(quote! {
+            impl #imp #krate::Diff for #from_ident #ty #wher {
+                fn diff(&self, other: &Self) -> Self::Item {
+                    #to_ident {
+                        #field_diffs
+                    }
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,117 @@
+use proc_macro2::Literal;
+use quote::{quote, ToTokens};
+use syn::{parse_quote, Generics, Ident, Path};
+
+use super::{
+    field_receiver::FieldReceiver,
+    token_vec::{Separator, TokenVec},
+};
+
+pub struct ImplFieldNames<'a> {
+    pub krate: &'a Option<Path>,
+    pub generics: &'a Generics,
+    pub to_ident: &'a Ident,
+
+    /// Note: assumed to already be filtered (such that `omit`-ted entries are removed)
+    pub fields: &'a Vec<&'a FieldReceiver>,
+}
+
+impl<'a> ToTokens for ImplFieldNames<'a> {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let Self {
+            krate,
+            to_ident,
+            generics,
+            fields,
+        } = self;
+
+        let (imp, ty, wher) = generics.split_for_impl();
+
+        // parse the crate config, or use `partially` for the crate path
+        let krate = if let Some(krate) = krate {
+            krate.to_owned()
+        } else {
+            parse_quote!(partially)
+        };
+
+        // the generated struct uses the renamed identifiers
+        let field_names = fields.iter().map(|f| f.dst_name()).collect();
+        let field_names = TokenVec::new_with_vec_and_sep(field_names, Separator::Comma);
+
+        let field_is_somes = fields
+            .iter()
+            .enumerate()
+            .map(|(index, f)| {
+                let index = Literal::usize_unsuffixed(index);
+                let to_member = f.dst_member();
+                let is_some = f.to_is_some(quote!(self.#to_member));
+
+                quote! {
+                    #index => #is_some
+                }
+            })
+            .chain(core::iter::once(quote!(_ => false)))
+            .collect();
+        let field_is_somes =
+            TokenVec::new_with_vec_and_sep(field_is_somes, Separator::CommaNewline);
+
+        // paths into `nested` fields are forwarded to their own implementation, which the default can't do
+        let nested_path_is_somes: Vec<_> = fields
+            .iter()
+            .filter(|f| f.nested.is_present())
+            .map(|f| {
+                let name = f.dst_name();
+                let to_member = f.dst_member();
+
+                let is_some = if f.is_dst_option() {
+                    quote! {
+                        match &self.#to_member {
+                            Some(nested) => #krate::FieldNames::path_is_some(nested, path),
+                            None => false,
+                        }
+                    }
+                } else {
+                    quote!(#krate::FieldNames::path_is_some(&self.#to_member, path))
+                };
+
+                quote! {
+                    Some((#name, path)) => #is_some
+                }
+            })
+            .collect();
+
+        let path_is_some = if nested_path_is_somes.is_empty() {
+            quote!()
+        } else {
+            let nested_path_is_somes =
+                TokenVec::new_with_vec_and_sep(nested_path_is_somes, Separator::CommaNewline);
+
+            quote! {
+                fn path_is_some(&self, path: &str) -> bool {
+                    match path.split_once('.') {
+                        #nested_path_is_somes,
+                        Some(_) => false,
+                        None => Self::FIELD_NAMES
+                            .iter()
+                            .position(|name| *name == path)
+                            .is_some_and(|index| #krate::FieldNames::field_is_some(self, index)),
+                    }
+                }
+            }
+        };
+
+        tokens.extend(quote! {
+            impl #imp #krate::FieldNames for #to_ident #ty #wher {
+                const FIELD_NAMES: &'static [&'static str] = &[#field_names];
+
+                fn field_is_some(&self, index: usize) -> bool {
+                    match index {
+                        #field_is_somes
+                    }
+                }
+
+                #path_is_some
+            }
+        })
+    }
+}
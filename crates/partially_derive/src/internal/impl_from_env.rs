@@ -0,0 +1,120 @@
+use quote::{quote, ToTokens};
+use syn::{parse_quote, Generics, Ident, Path};
+
+use super::{
+    field_receiver::FieldReceiver,
+    phantom_marker::PhantomMarker,
+    token_vec::{Separator, TokenVec},
+};
+
+pub struct ImplFromEnv<'a> {
+    pub krate: &'a Option<Path>,
+    pub generics: &'a Generics,
+    pub to_ident: &'a Ident,
+
+    /// Note: assumed to already be filtered (such that `omit`-ted entries are removed)
+    pub fields: &'a Vec<&'a FieldReceiver>,
+
+    /// The `PhantomData` marker of the generated struct, if any, which is filled when building it.
+    pub marker: &'a Option<PhantomMarker>,
+}
+
+impl<'a> ToTokens for ImplFromEnv<'a> {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let Self {
+            krate,
+            to_ident,
+            generics,
+            fields,
+            marker,
+        } = self;
+
+        let (imp, ty, wher) = generics.split_for_impl();
+
+        // parse the crate config, or use `partially` for the crate path
+        let krate = if let Some(krate) = krate {
+            krate.to_owned()
+        } else {
+            parse_quote!(partially)
+        };
+
+        let field_nones = fields
+            .iter()
+            .map(|f| {
+                let to_member = f.dst_member();
+                let none = f.to_field(quote!(None));
+
+                quote! {
+                    #to_member: #none
+                }
+            })
+            .chain(marker.iter().map(|m| m.to_value()))
+            .collect();
+        let field_nones = TokenVec::new_with_vec_and_sep(field_nones, Separator::CommaNewline);
+
+        let field_lookups = fields
+            .iter()
+            .map(|f| {
+                let to_ident = f.dst_ident();
+                let to_member = f.dst_member();
+
+                // variables use the upper case name of the generated field
+                let name = f.dst_name().to_uppercase();
+
+                if f.nested.is_present() {
+                    let src_type = &f.ty;
+
+                    quote! {
+                        let (#to_ident, is_nested_some) = <<#src_type as #krate::Partial>::Item as #krate::FromEnv>::from_env_lookup(
+                            &#krate::__private::env_nested_prefix(prefix, #name),
+                            lookup,
+                        )?;
+                        if is_nested_some {
+                            partial.#to_member = Some(#to_ident);
+                            is_some = true;
+                        }
+                    }
+                } else {
+                    let value = if f.patch.is_present() {
+                        quote!(#krate::Patch::Value(#krate::__private::parse_env(&name, &value)?))
+                    } else {
+                        quote!(Some(#krate::__private::parse_env(&name, &value)?))
+                    };
+
+                    quote! {
+                        let name = #krate::__private::env_name(prefix, #name);
+                        if let Some(value) = lookup(&name) {
+                            partial.#to_member = #value;
+                            is_some = true;
+                        }
+                    }
+                }
+            })
+            .collect();
+        let field_lookups = TokenVec::new_with_vec_and_sep(field_lookups, Separator::Newline);
+
+        let (partial, is_some) = if fields.is_empty() {
+            (quote!(partial), quote!(is_some))
+        } else {
+            (quote!(mut partial), quote!(mut is_some))
+        };
+
+        tokens.extend(quote! {
+            impl #imp #krate::FromEnv for #to_ident #ty #wher {
+                fn from_env_lookup(
+                    prefix: &str,
+                    lookup: &mut dyn FnMut(&str) -> Option<#krate::__private::String>,
+                ) -> Result<(Self, bool), #krate::EnvError> {
+                    let #partial = Self {
+                        #field_nones
+                    };
+                    let #is_some = false;
+
+                    #field_lookups
+
+                    Ok((partial, is_some))
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,150 @@
+use proc_macro2::Span;
+use quote::{quote, ToTokens};
+use syn::{parse_quote, Generics, Ident, LitStr, Path};
+
+use super::{
+    field_receiver::{FieldReceiver, Transparent},
+    phantom_marker::PhantomMarker,
+    token_vec::{Separator, TokenVec},
+};
+
+pub struct ImplMerge<'a> {
+    pub krate: &'a Option<Path>,
+    pub generics: &'a Generics,
+    pub to_ident: &'a Ident,
+
+    /// Note: assumed to already be filtered (such that `omit`-ted entries are removed)
+    pub fields: &'a Vec<&'a FieldReceiver>,
+
+    /// The `PhantomData` marker of the generated struct, if any, which is filled when building it.
+    pub marker: &'a Option<PhantomMarker>,
+}
+
+impl<'a> ToTokens for ImplMerge<'a> {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let Self {
+            krate,
+            to_ident,
+            generics,
+            fields,
+            marker,
+        } = self;
+
+        let (imp, ty, wher) = generics.split_for_impl();
+
+        // parse the crate config, or use `partially` for the crate path
+        let krate = if let Some(krate) = krate {
+            krate.to_owned()
+        } else {
+            parse_quote!(partially)
+        };
+
+        let field_mergers = fields
+            .iter()
+            .map(|f| {
+                let to_member = f.dst_member();
+                let name = LitStr::new(&f.dst_name(), Span::call_site());
+
+                // conflicts of nested fields are prefixed with the name of the nested field
+                let resolved = if f.nested.is_present() {
+                    quote! {
+                        #krate::Merge::merge(left, right, policy)
+                            .map_err(|conflict| #krate::MergeConflict::nested(conflict, #name))?
+                    }
+                } else {
+                    quote!(#krate::MergePolicy::resolve(policy, #name, left, right)?)
+                };
+
+                // transparent fields that aren't checked for `Some` are resolved directly
+                match f.transparent {
+                    Some(Transparent::Always) => {
+                        return quote! {
+                            #to_member: #krate::MergePolicy::resolve(policy, #name, self.#to_member, other.#to_member)?
+                        };
+                    }
+                    Some(Transparent::Never) => {
+                        return quote! {
+                            #to_member: self.#to_member
+                        };
+                    }
+                    Some(Transparent::WhenSome) | None => {}
+                }
+
+                let left = f.to_option(quote!(self.#to_member));
+                let right = f.to_option(quote!(other.#to_member));
+
+                let merged = f.to_field(quote! {
+                    match (#left, #right) {
+                        (Some(left), Some(right)) => Some(#resolved),
+                        (left, right) => left.or(right),
+                    }
+                });
+
+                quote! {
+                    #to_member: #merged
+                }
+            })
+            .chain(marker.iter().map(|m| m.to_value()))
+            .collect();
+        let field_mergers = TokenVec::new_with_vec_and_sep(field_mergers, Separator::CommaNewline);
+
+        // overlaying keeps the right value, like merging with `MergePolicy::RightWins`, so nothing conflicts
+        let field_overlays = fields
+            .iter()
+            .map(|f| {
+                let to_member = f.dst_member();
+
+                match f.transparent {
+                    Some(Transparent::Always) => {
+                        return quote! {
+                            #to_member: other.#to_member
+                        };
+                    }
+                    Some(Transparent::Never) => {
+                        return quote! {
+                            #to_member: self.#to_member
+                        };
+                    }
+                    Some(Transparent::WhenSome) | None => {}
+                }
+
+                let left = f.to_option(quote!(self.#to_member));
+                let right = f.to_option(quote!(other.#to_member));
+
+                let overlaid = if f.nested.is_present() {
+                    quote! {
+                        match (#left, #right) {
+                            (Some(left), Some(right)) => Some(#krate::Merge::overlay(left, right)),
+                            (left, right) => right.or(left),
+                        }
+                    }
+                } else {
+                    f.to_field(quote!(#right.or(#left)))
+                };
+
+                quote! {
+                    #to_member: #overlaid
+                }
+            })
+            .chain(marker.iter().map(|m| m.to_value()))
+            .collect();
+        let field_overlays =
+            TokenVec::new_with_vec_and_sep(field_overlays, Separator::CommaNewline);
+
+        tokens.extend(quote! {
+            impl #imp #krate::Merge for #to_ident #ty #wher {
+                fn merge(self, other: Self, policy: #krate::MergePolicy) -> Result<Self, #krate::MergeConflict> {
+                    Ok(Self {
+                        #field_mergers
+                    })
+                }
+
+                fn overlay(self, other: Self) -> Self {
+                    Self {
+                        #field_overlays
+                    }
+                }
+            }
+        })
+    }
+}
@@ -1,83 +1,138 @@
-use quote::{quote, ToTokens};
-use syn::{parse_quote, Generics, Ident, Path};
-
-use super::{
-    field_receiver::FieldReceiver,
-    token_vec::{Separator, TokenVec},
-};
-
-pub struct ImplPartial<'a> {
-    pub krate: &'a Option<Path>,
-    pub generics: &'a Generics,
-    pub from_ident: &'a Ident,
-    pub to_ident: &'a Ident,
-
-    /// Note: assumed to already be filtered (such that `omit`-ted entries are removed)
-    pub fields: &'a Vec<&'a FieldReceiver>,
-}
-
-impl<'a> ToTokens for ImplPartial<'a> {
-    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
-        let Self {
-            krate,
-            from_ident,
-            to_ident,
-            generics,
-            fields,
-        } = self;
-
-        let (imp, ty, wher) = generics.split_for_impl();
-
-        // parse the crate config, or use `partially` for the crate path
-        let krate = if let Some(krate) = krate {
-            krate.to_owned()
-        } else {
-            parse_quote!(partially)
-        };
-
-        let field_is_somes = fields
-            .iter()
-            .map(|f| {
-                // this is enforced with a better error by [`FieldReceiver::validate`].
-                let from_ident = f.ident.as_ref().unwrap();
-
-                let to_ident = f.rename.as_ref().unwrap_or(from_ident);
-
-                quote!(partial.#to_ident.is_some())
-            })
-            .collect();
-        let field_is_somes = TokenVec::new_with_vec_and_sep(field_is_somes, Separator::Or);
-
-        let field_applicators = fields
-            .iter()
-            .map(|f| {
-                // this is enforced with a better error by [`FieldReceiver::validate`].
-                let from_ident = f.ident.as_ref().unwrap();
-
-                let to_ident = f.rename.as_ref().unwrap_or(from_ident);
-
-                quote! {
-                    if let Some(#to_ident) = partial.#to_ident {
-                        self.#from_ident = #to_ident.into();
-                    }
-                }
-            })
-            .collect();
-        let field_applicators =
-            TokenVec::new_with_vec_and_sep(field_applicators, Separator::Newline);
-
-        tokens.extend(quote! {
-            impl #imp #krate::Partial for #from_ident #ty #wher {
-                type Item = #to_ident #ty;
-
-                fn apply_some(&mut self, partial: Self::Item) -> bool {
-                    let will_apply_some = #field_is_somes;
-
-                    #field_applicators
-
-                    will_apply_some
-                }
-            }
-        })
-    }
-}
+use quote::{quote, ToTokens};
+use syn::{parse_quote, Generics, Ident, Path};
+
+use super::{
+    field_receiver::FieldReceiver,
+    token_vec::{Separator, TokenVec},
+};
+
+pub struct ImplPartial<'a> {
+    pub krate: &'a Option<Path>,
+    pub generics: &'a Generics,
+    pub from_ident: &'a Ident,
+    pub to_ident: &'a Ident,
+
+    /// Note: assumed to already be filtered (such that `omit`-ted entries are removed)
+    pub fields: &'a Vec<&'a FieldReceiver>,
+
+    /// Whether the implementation is for the base struct, rather than the generated struct.
+    ///
+    /// Note: When `true`, `nested` fields are applied using their own `Partial` implementation and
+    /// fallible fields (using `try_into` or `validate`) are only applied when they convert and validate successfully.
+    /// When `false`, these fields are overwritten like any other field.
+    pub is_base: bool,
+
+    /// Whether values are compared with the current values, such that only changed fields are applied.
+    ///
+    /// Note: Only used when [`Self::is_base`] is `true`.
+    pub compare: bool,
+}
+
+impl<'a> ToTokens for ImplPartial<'a> {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let Self {
+            krate,
+            from_ident,
+            to_ident,
+            generics,
+            fields,
+            is_base,
+            compare,
+        } = self;
+
+        let (imp, ty, wher) = generics.split_for_impl();
+
+        // parse the crate config, or use `partially` for the crate path
+        let krate = if let Some(krate) = krate {
+            krate.to_owned()
+        } else {
+            parse_quote!(partially)
+        };
+
+        let is_recursive = |f: &FieldReceiver| *is_base && f.nested.is_present();
+        let is_fallible = |f: &FieldReceiver| *is_base && f.is_fallible();
+        let is_compared = |f: &FieldReceiver| *is_base && *compare && !f.nested.is_present();
+
+        // these applicators update `will_apply_some` with their own result
+        let is_tracked = |f: &FieldReceiver| is_recursive(f) || is_fallible(f) || is_compared(f);
+
+        let field_is_somes: Vec<_> = fields
+            .iter()
+            .filter(|f| !is_tracked(f))
+            .map(|f| {
+                let to_member = f.dst_member();
+
+                f.to_is_some(quote!(partial.#to_member))
+            })
+            .collect();
+        let field_is_somes = if field_is_somes.is_empty() {
+            quote!(false)
+        } else {
+            let field_is_somes = TokenVec::new_with_vec_and_sep(field_is_somes, Separator::Or);
+            quote!(#field_is_somes)
+        };
+
+        let will_apply_some = if fields.iter().any(|f| is_tracked(f)) {
+            quote!(mut will_apply_some)
+        } else {
+            quote!(will_apply_some)
+        };
+
+        let field_applicators = fields
+            .iter()
+            .map(|f| {
+                let to_ident = f.dst_ident();
+                let to_member = f.dst_member();
+
+                // the generated struct uses the renamed identifier
+                let from_member = if *is_base {
+                    f.src_member()
+                } else {
+                    to_member.clone()
+                };
+
+                let value = quote!(partial.#to_member);
+
+                let applicator = if is_recursive(f) {
+                    quote! {
+                        will_apply_some |= #krate::Partial::apply_some(&mut self.#from_member, #to_ident);
+                    }
+                } else if is_tracked(f) {
+                    f.to_applicator(
+                        quote!(self.#from_member),
+                        *compare,
+                        quote!(will_apply_some = true;),
+                    )
+                } else if !*is_base && f.patch.is_present() {
+                    let patch = f.to_field(quote!(Some(#to_ident)));
+
+                    quote! {
+                        self.#from_member = #patch;
+                    }
+                } else {
+                    quote! {
+                        self.#from_member = #to_ident.into();
+                    }
+                };
+
+                f.to_if_some(value, applicator)
+            })
+            .collect();
+        let field_applicators =
+            TokenVec::new_with_vec_and_sep(field_applicators, Separator::Newline);
+
+        tokens.extend(quote! {
+            impl #imp #krate::Partial for #from_ident #ty #wher {
+                type Item = #to_ident #ty;
+
+                fn apply_some(&mut self, partial: Self::Item) -> bool {
+                    let #will_apply_some = #field_is_somes;
+
+                    #field_applicators
+
+                    will_apply_some
+                }
+            }
+        })
+    }
+}
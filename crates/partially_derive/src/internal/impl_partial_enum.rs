@@ -0,0 +1,363 @@
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::{parse_quote, Generics, Ident, Member, Path};
+
+use super::{
+    field_receiver::{FieldReceiver, Transparent},
+    phantom_marker::PhantomMarker,
+    token_vec::{Separator, TokenVec},
+    variant_receiver::VariantReceiver,
+};
+
+pub struct ImplPartialEnum<'a> {
+    pub krate: &'a Option<Path>,
+    pub generics: &'a Generics,
+    pub from_ident: &'a Ident,
+    pub to_ident: &'a Ident,
+    pub variants: &'a Vec<&'a VariantReceiver>,
+
+    /// The `PhantomData` marker of the generated enum, if any, whose variant is never constructed.
+    pub marker: &'a Option<PhantomMarker>,
+
+    /// Whether the implementation is for the base enum, rather than the generated enum.
+    ///
+    /// Note: When `true`, a partial of a different variant only replaces the value when each of its fields is present
+    /// (and converts and validates successfully). When `false`, a partial of a different variant always replaces the value.
+    pub is_base: bool,
+
+    /// Whether values are compared with the current values, such that only changed fields are applied.
+    ///
+    /// Note: Only used when [`Self::is_base`] is `true`.
+    pub compare: bool,
+
+    /// Whether to implement `TryPartial` rather than `Partial`, returning a `VariantError` when a partial of a
+    /// different variant doesn't replace the value.
+    ///
+    /// Note: Only used when [`Self::is_base`] is `true`.
+    pub is_try: bool,
+}
+
+impl<'a> ImplPartialEnum<'a> {
+    /// Wraps `applied` (whether any field was applied) in the return type of the implementation.
+    fn to_applied(&self, applied: TokenStream) -> TokenStream {
+        if self.is_try {
+            quote!(Ok(#applied))
+        } else {
+            applied
+        }
+    }
+
+    /// Generates statements that apply the fields of a partial to the fields of the same variant in place, evaluating
+    /// to whether any field was applied.
+    fn to_patch(&self, krate: &Path, fields: &[&FieldReceiver]) -> TokenStream {
+        let applied: Vec<_> = fields
+            .iter()
+            .filter(|f| f.transparent != Some(Transparent::Never))
+            .collect();
+
+        if applied.is_empty() {
+            return self.to_applied(quote!(false));
+        }
+
+        let field_applicators = applied
+            .iter()
+            .map(|f| {
+                let to_ident = f.dst_ident();
+                let binding = VariantReceiver::src_binding(f);
+
+                let applicator = if self.is_base && f.nested.is_present() {
+                    quote! {
+                        will_apply_some |= #krate::Partial::apply_some(#binding, #to_ident);
+                    }
+                } else if self.is_base {
+                    f.to_applicator(
+                        quote!(*#binding),
+                        self.compare,
+                        quote!(will_apply_some = true;),
+                    )
+                } else if f.patch.is_present() {
+                    let patch = f.to_field(quote!(Some(#to_ident)));
+
+                    quote! {
+                        *#binding = #patch;
+                        will_apply_some = true;
+                    }
+                } else {
+                    quote! {
+                        *#binding = #to_ident.into();
+                        will_apply_some = true;
+                    }
+                };
+
+                f.to_if_some(quote!(#to_ident), applicator)
+            })
+            .collect();
+        let field_applicators =
+            TokenVec::new_with_vec_and_sep(field_applicators, Separator::Newline);
+        let will_apply_some = self.to_applied(quote!(will_apply_some));
+
+        quote! {
+            let mut will_apply_some = false;
+
+            #field_applicators
+
+            #will_apply_some
+        }
+    }
+
+    /// Generates statements that replace the value with a variant built from the fields of a partial, evaluating to
+    /// whether the value was replaced (or to a `VariantError`, when [`Self::is_try`] is `true`).
+    fn to_replacement(&self, krate: &Path, variant: &VariantReceiver) -> TokenStream {
+        let ident = &variant.ident;
+        let replaced = self.to_applied(quote!(true));
+
+        if !self.is_base {
+            let field_values = variant
+                .dst_fields()
+                .iter()
+                .map(|f| {
+                    let to_ident = f.dst_ident();
+                    let to_member = f.dst_member();
+
+                    quote!(#to_member: #to_ident)
+                })
+                .collect();
+            let field_values =
+                TokenVec::new_with_vec_and_sep(field_values, Separator::CommaNewline);
+
+            return quote! {
+                *self = Self::#ident {
+                    #field_values
+                };
+                true
+            };
+        }
+
+        // values of `omit`-ted fields, and fields that are never applied, aren't known
+        let is_defaulted =
+            |f: &FieldReceiver| f.omit.is_present() || f.transparent == Some(Transparent::Never);
+
+        let field_values = variant
+            .fields
+            .iter()
+            .map(|f| {
+                let from_member = f.src_member();
+                let to_ident = f.dst_ident();
+
+                if is_defaulted(f) {
+                    let default = f
+                        .default
+                        .as_ref()
+                        .map(|default| quote!(#default))
+                        .unwrap_or_else(|| quote!(Default::default()));
+
+                    quote!(#from_member: #default)
+                } else {
+                    quote!(#from_member: #to_ident)
+                }
+            })
+            .collect();
+        let field_values = TokenVec::new_with_vec_and_sep(field_values, Separator::CommaNewline);
+
+        let required: Vec<_> = variant
+            .fields
+            .iter()
+            .filter(|f| !is_defaulted(f) && f.transparent.is_none())
+            .collect();
+
+        if required.is_empty() {
+            return quote! {
+                *self = Self::#ident {
+                    #field_values
+                };
+                #replaced
+            };
+        }
+
+        // each field is converted (and validated) before the value is replaced, such that failures leave it unchanged
+        let replacer = required.iter().rev().fold(
+            quote! {
+                *self = Self::#ident {
+                    #field_values
+                };
+                return #replaced;
+            },
+            |body, f| {
+                if f.nested.is_present() {
+                    let to_ident = f.dst_ident();
+                    let src_type = &f.ty;
+
+                    quote! {
+                        if let Ok(#to_ident) = <#src_type as core::convert::TryFrom<_>>::try_from(#to_ident) {
+                            #body
+                        }
+                    }
+                } else {
+                    f.to_converted(body)
+                }
+            },
+        );
+
+        let values = required
+            .iter()
+            .map(|f| {
+                let to_ident = f.dst_ident();
+
+                f.to_option(quote!(#to_ident))
+            })
+            .collect();
+        let values = TokenVec::new_with_vec_and_sep(values, Separator::Comma);
+
+        let somes = required
+            .iter()
+            .map(|f| {
+                let to_ident = f.dst_ident();
+
+                quote!(Some(#to_ident))
+            })
+            .collect();
+        let somes = TokenVec::new_with_vec_and_sep(somes, Separator::Comma);
+
+        let rejected = if self.is_try {
+            let name = ident.to_string();
+
+            quote!(Err(#krate::VariantError { variant: #name }))
+        } else {
+            quote!(false)
+        };
+
+        quote! {
+            if let (#somes,) = (#values,) {
+                #replacer
+            }
+
+            #rejected
+        }
+    }
+}
+
+impl<'a> ToTokens for ImplPartialEnum<'a> {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let Self {
+            krate,
+            from_ident,
+            to_ident,
+            generics,
+            variants,
+            marker,
+            ..
+        } = self;
+
+        let (imp, ty, wher) = generics.split_for_impl();
+
+        // parse the crate config, or use `partially` for the crate path
+        let krate = if let Some(krate) = krate {
+            krate.to_owned()
+        } else {
+            parse_quote!(partially)
+        };
+
+        let variant_arms = variants
+            .iter()
+            .map(|v| {
+                let ident = &v.ident;
+                let fields = v.dst_fields();
+
+                // fields that are never applied aren't used when applying the partial to the base enum
+                let is_ignored =
+                    |f: &FieldReceiver| self.is_base && f.transparent == Some(Transparent::Never);
+
+                let partial_bindings = fields
+                    .iter()
+                    .map(|f| {
+                        let to_ident = f.dst_ident();
+                        let to_member = f.dst_member();
+
+                        // named fields use the shorthand pattern, as they're bound to their own identifier
+                        match to_member {
+                            _ if is_ignored(f) => quote!(#to_member: _),
+                            Member::Named(_) => quote!(#to_ident),
+                            Member::Unnamed(_) => quote!(#to_member: #to_ident),
+                        }
+                    })
+                    .collect();
+                let partial_bindings =
+                    TokenVec::new_with_vec_and_sep(partial_bindings, Separator::Comma);
+
+                let self_bindings: Vec<_> = fields
+                    .iter()
+                    .filter(|f| f.transparent != Some(Transparent::Never))
+                    .map(|f| {
+                        let binding = VariantReceiver::src_binding(f);
+
+                        // the generated enum uses the renamed identifier
+                        let from_member = if self.is_base {
+                            f.src_member()
+                        } else {
+                            f.dst_member()
+                        };
+
+                        quote!(#from_member: #binding,)
+                    })
+                    .collect();
+                let self_bindings = TokenVec::new_with_vec(self_bindings);
+
+                let patch = self.to_patch(&krate, &fields);
+
+                // the value of an enum with a single variant is never replaced, so no replacement is built (which
+                // would require `nested` fields to implement `TryFrom`)
+                if self.is_base && variants.len() == 1 {
+                    return quote! {
+                        #to_ident::#ident { #partial_bindings } => {
+                            let Self::#ident { #self_bindings .. } = self;
+                            #patch
+                        }
+                    };
+                }
+
+                let replacement = self.to_replacement(&krate, v);
+
+                quote! {
+                    #to_ident::#ident { #partial_bindings } => {
+                        if let Self::#ident { #self_bindings .. } = self {
+                            #patch
+                        } else {
+                            #replacement
+                        }
+                    }
+                }
+            })
+            .chain(marker.iter().map(|m| m.to_variant_arm(to_ident)))
+            .collect();
+        let variant_arms = TokenVec::new_with_vec_and_sep(variant_arms, Separator::Newline);
+
+        if self.is_try {
+            tokens.extend(quote! {
+                impl #imp #krate::TryPartial for #from_ident #ty #wher {
+                    type Item = #to_ident #ty;
+                    type Error = #krate::VariantError;
+
+                    fn try_apply_some(&mut self, partial: Self::Item) -> Result<bool, Self::Error> {
+                        match partial {
+                            #variant_arms
+                        }
+                    }
+                }
+            });
+
+            return;
+        }
+
+        tokens.extend(quote! {
+            impl #imp #krate::Partial for #from_ident #ty #wher {
+                type Item = #to_ident #ty;
+
+                fn apply_some(&mut self, partial: Self::Item) -> bool {
+                    match partial {
+                        #variant_arms
+                    }
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,208 @@
+use proc_macro2::Span;
+use quote::{format_ident, quote, ToTokens};
+use syn::{parse_quote, Generics, Ident, LitInt, Path, Visibility};
+
+use super::{
+    field_receiver::FieldReceiver,
+    token_vec::{Separator, TokenVec},
+};
+
+/// The maximum number of fields supported by [`ImplReport`].
+pub const MAX_REPORT_FIELDS: usize = 128;
+
+pub struct ImplReport<'a> {
+    pub krate: &'a Option<Path>,
+    pub vis: &'a Visibility,
+    pub generics: &'a Generics,
+    pub from_ident: &'a Ident,
+    pub report_ident: &'a Ident,
+
+    /// Note: assumed to already be filtered (such that `omit`-ted entries are removed)
+    pub fields: &'a Vec<&'a FieldReceiver>,
+
+    /// Whether values are compared with the current values, such that only changed fields are applied and reported.
+    pub compare: bool,
+}
+
+impl<'a> ImplReport<'a> {
+    /// Gets the identifier of the constant representing the given field in the report struct.
+    ///
+    /// Note: For fields of tuple structs, this is `FIELD_{index}`.
+    pub fn field_const_ident(field: &FieldReceiver) -> Ident {
+        match &field.ident {
+            Some(from_ident) => {
+                let name = from_ident.to_string();
+                let name = name.trim_start_matches("r#").to_uppercase();
+
+                Ident::new(&name, from_ident.span())
+            }
+            None => format_ident!("FIELD_{}", field.index),
+        }
+    }
+}
+
+impl<'a> ToTokens for ImplReport<'a> {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let Self {
+            krate,
+            vis,
+            from_ident,
+            report_ident,
+            generics,
+            fields,
+            compare,
+        } = self;
+
+        let (imp, ty, wher) = generics.split_for_impl();
+
+        // parse the crate config, or use `partially` for the crate path
+        let krate = if let Some(krate) = krate {
+            krate.to_owned()
+        } else {
+            parse_quote!(partially)
+        };
+
+        // use the smallest integer that has a bit for every field
+        let bits_type: Ident = match fields.len() {
+            0..=8 => parse_quote!(u8),
+            9..=16 => parse_quote!(u16),
+            17..=32 => parse_quote!(u32),
+            33..=64 => parse_quote!(u64),
+            _ => parse_quote!(u128),
+        };
+
+        let field_consts = fields
+            .iter()
+            .enumerate()
+            .map(|(index, f)| {
+                let const_ident = Self::field_const_ident(f);
+                let index = LitInt::new(&index.to_string(), Span::call_site());
+                let doc = format!("The `{}` field.", f.src_name());
+
+                quote! {
+                    #[doc = #doc]
+                    pub const #const_ident: Self = Self(1 << #index);
+                }
+            })
+            .collect();
+        let field_consts = TokenVec::new_with_vec_and_sep(field_consts, Separator::Newline);
+
+        let all_bits = if fields.is_empty() {
+            quote!(0)
+        } else {
+            let field_bits = fields
+                .iter()
+                .map(|f| {
+                    let const_ident = Self::field_const_ident(f);
+
+                    quote!(Self::#const_ident.0)
+                })
+                .collect();
+            let field_bits = TokenVec::new_with_vec_and_sep(field_bits, Separator::BitOr);
+
+            quote!(#field_bits)
+        };
+
+        let field_applicators = fields
+            .iter()
+            .map(|f| {
+                let from_member = f.src_member();
+                let to_ident = f.dst_ident();
+                let to_member = f.dst_member();
+                let const_ident = Self::field_const_ident(f);
+
+                let on_applied = quote!(report.insert(#report_ident::#const_ident););
+
+                let applicator = if f.nested.is_present() {
+                    quote! {
+                        if #krate::Partial::apply_some(&mut self.#from_member, #to_ident) {
+                            #on_applied
+                        }
+                    }
+                } else {
+                    f.to_applicator(quote!(self.#from_member), *compare, on_applied)
+                };
+
+                f.to_if_some(quote!(partial.#to_member), applicator)
+            })
+            .collect();
+        let field_applicators =
+            TokenVec::new_with_vec_and_sep(field_applicators, Separator::Newline);
+
+        let doc = format!(
+            "The set of `{}` fields applied by `apply_some_report`.",
+            from_ident
+        );
+
+        tokens.extend(quote! {
+            #[doc = #doc]
+            #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+            #vis struct #report_ident(#bits_type);
+
+            impl #report_ident {
+                #field_consts
+
+                /// Gets an empty set of fields.
+                pub const fn empty() -> Self {
+                    Self(0)
+                }
+
+                /// Gets the set of all fields.
+                pub const fn all() -> Self {
+                    Self(#all_bits)
+                }
+
+                /// Gets the raw bits of the set.
+                pub const fn bits(self) -> #bits_type {
+                    self.0
+                }
+
+                /// Gets whether the set is empty.
+                pub const fn is_empty(self) -> bool {
+                    self.0 == 0
+                }
+
+                /// Gets whether the set contains every field in `other`.
+                pub const fn contains(self, other: Self) -> bool {
+                    self.0 & other.0 == other.0
+                }
+
+                /// Gets whether the set contains any field in `other`.
+                pub const fn intersects(self, other: Self) -> bool {
+                    self.0 & other.0 != 0
+                }
+
+                /// Adds every field in `other` to the set.
+                pub fn insert(&mut self, other: Self) {
+                    self.0 |= other.0;
+                }
+            }
+
+            impl core::ops::BitOr for #report_ident {
+                type Output = Self;
+
+                fn bitor(self, other: Self) -> Self {
+                    Self(self.0 | other.0)
+                }
+            }
+
+            impl core::ops::BitOrAssign for #report_ident {
+                fn bitor_assign(&mut self, other: Self) {
+                    self.insert(other)
+                }
+            }
+
+            impl #imp #krate::PartialReport for #from_ident #ty #wher {
+                type Report = #report_ident;
+
+                fn apply_some_report(&mut self, partial: Self::Item) -> Self::Report {
+                    let mut report = #report_ident::empty();
+
+                    #field_applicators
+
+                    report
+                }
+            }
+        })
+    }
+}
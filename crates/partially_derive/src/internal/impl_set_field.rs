@@ -0,0 +1,92 @@
+use quote::{quote, ToTokens};
+use syn::{parse_quote, GenericParam, Generics, Ident, Path};
+
+use super::{
+    field_receiver::FieldReceiver,
+    token_vec::{Separator, TokenVec},
+};
+
+pub struct ImplSetField<'a> {
+    pub krate: &'a Option<Path>,
+    pub generics: &'a Generics,
+    pub to_ident: &'a Ident,
+
+    /// Note: assumed to already be filtered (such that `omit`-ted entries are removed)
+    pub fields: &'a Vec<&'a FieldReceiver>,
+}
+
+impl<'a> ToTokens for ImplSetField<'a> {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let Self {
+            krate,
+            to_ident,
+            generics,
+            fields,
+        } = self;
+
+        // values are downcast from `Any`, so each generic parameter must be `'static`
+        let mut generics = (*generics).clone();
+        let params: Vec<_> = generics.params.iter().cloned().collect();
+        let predicates = &mut generics.make_where_clause().predicates;
+        for param in params {
+            match param {
+                GenericParam::Type(param) => {
+                    let ident = param.ident;
+                    predicates.push(parse_quote!(#ident: 'static));
+                }
+                GenericParam::Lifetime(param) => {
+                    let lifetime = param.lifetime;
+                    predicates.push(parse_quote!(#lifetime: 'static));
+                }
+                GenericParam::Const(_) => {}
+            }
+        }
+
+        let (imp, ty, wher) = generics.split_for_impl();
+
+        // parse the crate config, or use `partially` for the crate path
+        let krate = if let Some(krate) = krate {
+            krate.to_owned()
+        } else {
+            parse_quote!(partially)
+        };
+
+        let field_setters = fields
+            .iter()
+            .map(|f| {
+                let to_member = f.dst_member();
+                let name = f.dst_name();
+
+                let setter = if f.patch.is_present() {
+                    quote!(set_patch)
+                } else if f.is_dst_option() {
+                    quote!(set_option)
+                } else {
+                    quote!(set_value)
+                };
+
+                quote! {
+                    #name => #krate::__private::#setter(&mut self.#to_member, #name, value)
+                }
+            })
+            .chain(core::iter::once(
+                quote!(_ => Err(#krate::__private::unknown(name))),
+            ))
+            .collect();
+        let field_setters = TokenVec::new_with_vec_and_sep(field_setters, Separator::CommaNewline);
+
+        tokens.extend(quote! {
+            impl #imp #krate::SetField for #to_ident #ty #wher {
+                fn set_field(
+                    &mut self,
+                    name: &str,
+                    value: #krate::__private::Box<dyn #krate::__private::Any>,
+                ) -> Result<(), #krate::FieldError> {
+                    match name {
+                        #field_setters
+                    }
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,214 @@
+use proc_macro2::Span;
+use quote::{quote, ToTokens};
+use syn::{parse_quote, Generics, Ident, LitStr, Path};
+
+use super::{
+    field_receiver::FieldReceiver,
+    token_vec::{Separator, TokenVec},
+};
+
+/// The maximum number of fields that may be missing supported by [`ImplTryFrom`] (see `MissingFields::MAX_FIELDS`).
+pub const MAX_TRY_FROM_FIELDS: usize = 128;
+
+pub struct ImplTryFrom<'a> {
+    pub krate: &'a Option<Path>,
+    pub generics: &'a Generics,
+    pub from_ident: &'a Ident,
+    pub to_ident: &'a Ident,
+
+    /// Note: assumed to __not__ be filtered, as `omit`-ted entries are needed to construct the base struct.
+    pub fields: &'a Vec<&'a FieldReceiver>,
+}
+
+impl<'a> ToTokens for ImplTryFrom<'a> {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let Self {
+            krate,
+            from_ident,
+            to_ident,
+            generics,
+            fields,
+        } = self;
+
+        let (imp, ty, wher) = generics.split_for_impl();
+
+        // parse the crate config, or use `partially` for the crate path
+        let krate = if let Some(krate) = krate {
+            krate.to_owned()
+        } else {
+            parse_quote!(partially)
+        };
+
+        // fields that must be `Some` for the conversion to succeed
+        let required: Vec<_> = fields
+            .iter()
+            .filter(|f| !f.omit.is_present() && f.transparent.is_none())
+            .collect();
+
+        // nested and fallible fields are converted up front, such that failed conversions are reported as invalid
+        let field_converters = required
+            .iter()
+            .filter_map(|f| {
+                let to_ident = f.dst_ident();
+                let to_member = f.dst_member();
+                let src_type = &f.ty;
+                let name = LitStr::new(&f.dst_name(), to_ident.span());
+
+                let invalid = quote! {
+                    |error| #krate::TryFromPartialError::Invalid(#krate::InvalidField::new(#name, error))
+                };
+
+                if f.nested.is_present() {
+                    Some(quote! {
+                        let #to_ident: Option<#src_type> = partial.#to_member
+                            .map(core::convert::TryFrom::try_from)
+                            .transpose()
+                            .map_err(#invalid)?;
+                    })
+                } else if f.is_fallible() {
+                    let converter = if f.try_into.is_present() {
+                        quote! {
+                            let #to_ident: Option<#src_type> = partial.#to_member
+                                .map(core::convert::TryInto::try_into)
+                                .transpose()
+                                .map_err(#invalid)?;
+                        }
+                    } else {
+                        quote! {
+                            let #to_ident: Option<#src_type> = partial.#to_member.map(Into::into);
+                        }
+                    };
+
+                    let validator = f.validate.as_ref().map(|validate| {
+                        quote! {
+                            if let Some(#to_ident) = &#to_ident {
+                                #validate(#to_ident).map_err(#invalid)?;
+                            }
+                        }
+                    });
+
+                    Some(quote! {
+                        #converter
+                        #validator
+                    })
+                } else {
+                    None
+                }
+            })
+            .collect();
+        let field_converters = TokenVec::new_with_vec_and_sep(field_converters, Separator::Newline);
+
+        let field_values = fields
+            .iter()
+            .map(|f| {
+                let from_member = f.src_member();
+                let to_ident = f.dst_ident();
+                let to_member = f.dst_member();
+
+                if f.omit.is_present() {
+                    let default = f
+                        .default
+                        .as_ref()
+                        .map(|default| quote!(#default))
+                        .unwrap_or_else(|| quote!(Default::default()));
+
+                    quote!(#from_member: #default)
+                } else if f.transparent.is_some() {
+                    quote!(#from_member: partial.#to_member)
+                } else {
+                    quote!(#from_member: #to_ident.into())
+                }
+            })
+            .collect();
+        let field_values = TokenVec::new_with_vec_and_sep(field_values, Separator::CommaNewline);
+
+        let body = if required.is_empty() {
+            quote! {
+                Ok(Self {
+                    #field_values
+                })
+            }
+        } else {
+            let idents: Vec<_> = required.iter().map(|f| f.dst_ident()).collect();
+
+            let values = required
+                .iter()
+                .map(|f| {
+                    let to_ident = f.dst_ident();
+                    let to_member = f.dst_member();
+
+                    if f.nested.is_present() || f.is_fallible() {
+                        quote!(#to_ident)
+                    } else {
+                        f.to_option(quote!(partial.#to_member))
+                    }
+                })
+                .collect();
+            let values = TokenVec::new_with_vec_and_sep(values, Separator::Comma);
+
+            let somes = required
+                .iter()
+                .map(|f| {
+                    let to_ident = f.dst_ident();
+
+                    quote!(Some(#to_ident))
+                })
+                .collect();
+            let somes = TokenVec::new_with_vec_and_sep(somes, Separator::Comma);
+
+            let missing_pushes = required
+                .iter()
+                .map(|f| {
+                    let to_ident = f.dst_ident();
+                    let name = LitStr::new(&f.dst_name(), to_ident.span());
+
+                    quote! {
+                        if #to_ident.is_none() {
+                            missing.push(#name);
+                        }
+                    }
+                })
+                .collect();
+            let missing_pushes = TokenVec::new_with_vec_and_sep(missing_pushes, Separator::Newline);
+
+            let idents = TokenVec::new_with_vec_and_sep(idents, Separator::Comma);
+
+            let names = required
+                .iter()
+                .map(|f| {
+                    let name = LitStr::new(&f.dst_name(), Span::call_site());
+
+                    quote!(#name)
+                })
+                .collect();
+            let names = TokenVec::new_with_vec_and_sep(names, Separator::Comma);
+
+            quote! {
+                #field_converters
+
+                match (#values,) {
+                    (#somes,) => Ok(Self {
+                        #field_values
+                    }),
+                    (#idents,) => {
+                        let mut missing = #krate::MissingFields::new(&[#names]);
+
+                        #missing_pushes
+
+                        Err(#krate::TryFromPartialError::Missing(missing))
+                    }
+                }
+            }
+        };
+
+        tokens.extend(quote! {
+            impl #imp core::convert::TryFrom<#to_ident #ty> for #from_ident #ty #wher {
+                type Error = #krate::TryFromPartialError;
+
+                fn try_from(partial: #to_ident #ty) -> Result<Self, Self::Error> {
+                    #body
+                }
+            }
+        })
+    }
+}
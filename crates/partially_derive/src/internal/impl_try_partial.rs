@@ -0,0 +1,192 @@
+use quote::{quote, ToTokens};
+use syn::{parse_quote, Generics, Ident, Path, Type};
+
+use super::{
+    field_receiver::FieldReceiver,
+    token_vec::{Separator, TokenVec},
+};
+
+pub struct ImplTryPartial<'a> {
+    pub krate: &'a Option<Path>,
+    pub generics: &'a Generics,
+    pub from_ident: &'a Ident,
+    pub to_ident: &'a Ident,
+    pub error: &'a Type,
+
+    /// Note: assumed to already be filtered (such that `omit`-ted entries are removed)
+    pub fields: &'a Vec<&'a FieldReceiver>,
+
+    /// Whether values are compared with the current values, such that only changed fields are applied.
+    pub compare: bool,
+}
+
+impl<'a> ToTokens for ImplTryPartial<'a> {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let Self {
+            krate,
+            from_ident,
+            to_ident,
+            generics,
+            error,
+            fields,
+            compare,
+        } = self;
+
+        let (imp, ty, wher) = generics.split_for_impl();
+
+        // parse the crate config, or use `partially` for the crate path
+        let krate = if let Some(krate) = krate {
+            krate.to_owned()
+        } else {
+            parse_quote!(partially)
+        };
+
+        // these applicators update `will_apply_some` with their own result
+        let is_tracked = |f: &FieldReceiver| *compare || f.nested.is_present();
+
+        // compared applicators only update `will_apply_some` when a value actually changes
+        let on_applied = if *compare {
+            quote!(will_apply_some = true;)
+        } else {
+            quote!()
+        };
+
+        let field_is_somes: Vec<_> = fields
+            .iter()
+            .filter(|f| !is_tracked(f))
+            .map(|f| {
+                let to_member = f.dst_member();
+
+                f.to_is_some(quote!(partial.#to_member))
+            })
+            .collect();
+        let field_is_somes = if field_is_somes.is_empty() {
+            quote!(false)
+        } else {
+            let field_is_somes = TokenVec::new_with_vec_and_sep(field_is_somes, Separator::Or);
+            quote!(#field_is_somes)
+        };
+
+        let will_apply_some = if fields.iter().any(|f| is_tracked(f)) {
+            quote!(mut will_apply_some)
+        } else {
+            quote!(will_apply_some)
+        };
+
+        // fallible fields are converted and validated before anything is applied, so that
+        // an error leaves `self` untouched
+        let field_converters = fields
+            .iter()
+            .filter(|f| f.is_fallible() || f.nested.is_present())
+            .map(|f| {
+                let from_member = f.src_member();
+                let to_ident = f.dst_ident();
+                let to_member = f.dst_member();
+                let src_type = &f.ty;
+
+                // nested fields are applied to a copy, which replaces the field once nothing else can fail
+                if f.nested.is_present() {
+                    return quote! {
+                        let #to_ident: Option<(#src_type, bool)> = match partial.#to_member {
+                            Some(#to_ident) => {
+                                let mut value = self.#from_member.clone();
+                                let applied = #krate::TryPartial::try_apply_some(&mut value, #to_ident)?;
+
+                                Some((value, applied))
+                            }
+                            None => None,
+                        };
+                    };
+                }
+
+                let converter = if f.try_into.is_present() {
+                    quote! {
+                        let #to_ident: Option<#src_type> = partial.#to_member
+                            .map(core::convert::TryInto::try_into)
+                            .transpose()?;
+                    }
+                } else {
+                    quote! {
+                        let #to_ident: Option<#src_type> = partial.#to_member.map(Into::into);
+                    }
+                };
+
+                let validator = f.validate.as_ref().map(|validate| {
+                    quote! {
+                        if let Some(#to_ident) = &#to_ident {
+                            #validate(#to_ident)?;
+                        }
+                    }
+                });
+
+                quote! {
+                    #converter
+                    #validator
+                }
+            })
+            .collect();
+        let field_converters = TokenVec::new_with_vec_and_sep(field_converters, Separator::Newline);
+
+        let field_applicators = fields
+            .iter()
+            .map(|f| {
+                let from_member = f.src_member();
+                let to_ident = f.dst_ident();
+                let to_member = f.dst_member();
+
+                let value = quote!(partial.#to_member);
+
+                if f.nested.is_present() {
+                    // nested fields are already applied to a copy, and bound to their own identifier
+                    quote! {
+                        if let Some((value, applied)) = #to_ident {
+                            self.#from_member = value;
+                            will_apply_some |= applied;
+                        }
+                    }
+                } else if f.is_fallible() {
+                    // fallible fields are already converted, and bound to their own identifier
+                    let assignment =
+                        f.to_assignment(quote!(self.#from_member), *compare, on_applied.clone());
+
+                    quote! {
+                        if let Some(#to_ident) = #to_ident {
+                            #assignment
+                        }
+                    }
+                } else if *compare {
+                    f.to_if_some(
+                        value,
+                        f.to_applicator(quote!(self.#from_member), true, on_applied.clone()),
+                    )
+                } else {
+                    f.to_if_some(
+                        value,
+                        quote! {
+                            self.#from_member = #to_ident.into();
+                        },
+                    )
+                }
+            })
+            .collect();
+        let field_applicators =
+            TokenVec::new_with_vec_and_sep(field_applicators, Separator::Newline);
+
+        tokens.extend(quote! {
+            impl #imp #krate::TryPartial for #from_ident #ty #wher {
+                type Item = #to_ident #ty;
+                type Error = #error;
+
+                fn try_apply_some(&mut self, partial: Self::Item) -> Result<bool, Self::Error> {
+                    let #will_apply_some = #field_is_somes;
+
+                    #field_converters
+
+                    #field_applicators
+
+                    Ok(will_apply_some)
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,117 @@
+use quote::{quote, ToTokens};
+use syn::{parse_quote, Generics, Ident, Path};
+
+use super::{
+    field_receiver::FieldReceiver,
+    phantom_marker::PhantomMarker,
+    token_vec::{Separator, TokenVec},
+};
+
+pub struct ImplUndo<'a> {
+    pub krate: &'a Option<Path>,
+    pub generics: &'a Generics,
+    pub from_ident: &'a Ident,
+    pub to_ident: &'a Ident,
+
+    /// Note: assumed to already be filtered (such that `omit`-ted entries are removed)
+    pub fields: &'a Vec<&'a FieldReceiver>,
+
+    /// The `PhantomData` marker of the generated struct, if any, which is filled when building it.
+    pub marker: &'a Option<PhantomMarker>,
+
+    /// Whether values are compared with the current values, such that only changed fields are applied and recorded.
+    pub compare: bool,
+}
+
+impl<'a> ToTokens for ImplUndo<'a> {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let Self {
+            krate,
+            from_ident,
+            to_ident,
+            generics,
+            fields,
+            marker,
+            compare,
+        } = self;
+
+        let (imp, ty, wher) = generics.split_for_impl();
+
+        // parse the crate config, or use `partially` for the crate path
+        let krate = if let Some(krate) = krate {
+            krate.to_owned()
+        } else {
+            parse_quote!(partially)
+        };
+
+        let field_nones = fields
+            .iter()
+            .map(|f| {
+                let to_member = f.dst_member();
+                let none = f.to_field(quote!(None));
+
+                quote!(#to_member: #none)
+            })
+            .chain(marker.iter().map(|m| m.to_value()))
+            .collect();
+        let field_nones = TokenVec::new_with_vec_and_sep(field_nones, Separator::CommaNewline);
+
+        let field_applicators = fields
+            .iter()
+            .map(|f| {
+                let from_member = f.src_member();
+                let to_ident = f.dst_ident();
+                let to_member = f.dst_member();
+
+                let applicator = if f.nested.is_present() {
+                    quote! {
+                        undo.#to_member = Some(#krate::PartialUndo::apply_some_with_undo(&mut self.#from_member, #to_ident));
+                    }
+                } else {
+                    // the previous value is recorded as it's replaced
+                    let previous = f.to_field(
+                        quote!(Some(core::mem::replace(&mut self.#from_member, #to_ident))),
+                    );
+                    let mut replacer = quote! {
+                        undo.#to_member = #previous;
+                    };
+
+                    if *compare {
+                        replacer = quote! {
+                            if self.#from_member != #to_ident {
+                                #replacer
+                            }
+                        };
+                    }
+
+                    f.to_converted(replacer)
+                };
+
+                f.to_if_some(quote!(partial.#to_member), applicator)
+            })
+            .collect();
+        let field_applicators =
+            TokenVec::new_with_vec_and_sep(field_applicators, Separator::Newline);
+
+        // applicators record previous values into `undo`
+        let undo = if fields.is_empty() {
+            quote!(undo)
+        } else {
+            quote!(mut undo)
+        };
+
+        tokens.extend(quote! {
+            impl #imp #krate::PartialUndo for #from_ident #ty #wher {
+                fn apply_some_with_undo(&mut self, partial: Self::Item) -> Self::Item {
+                    let #undo = #to_ident {
+                        #field_nones
+                    };
+
+                    #field_applicators
+
+                    undo
+                }
+            }
+        })
+    }
+}
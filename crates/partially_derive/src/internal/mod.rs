@@ -1,534 +1,2528 @@
-use darling::FromDeriveInput;
-use proc_macro2::TokenStream;
-use quote::quote;
-use syn::DeriveInput;
-
-use self::derive_receiver::DeriveReceiver;
-
-mod derive_receiver;
-mod field_receiver;
-mod impl_partial;
-mod meta_attribute;
-mod token_vec;
-
-pub fn expand_derive_partial(item: &mut DeriveInput) -> TokenStream {
-    let maybe_receiver = DeriveReceiver::from_derive_input(item);
-
-    match maybe_receiver {
-        Ok(receiver) => quote!(#receiver),
-        Err(e) => e.write_errors(),
-    }
-}
-
-#[cfg(test)]
-mod test {
-    use proc_macro2::TokenStream;
-    use syn::{parse_quote, DeriveInput};
-
-    use super::expand_derive_partial;
-
-    #[test]
-    fn basic_e2e() {
-        let mut input: DeriveInput = parse_quote! {
-            #[derive(partially::Partial, Default, Debug)]
-            #[partially(derive(Default, Debug))]
-            #[partially(attribute(serde(default)))]
-            #[some_attr]
-            struct Data {
-                /// A documented field.
-                #[some_attr]
-                str_field: String,
-                #[partially(omit)]
-                skipped_field: String,
-                #[partially(as_type = "Option<f32>")]
-                #[some_attr]
-                number_field: i32,
-                #[partially(transparent)]
-                transparent_field: Option<String>,
-                #[partially(rename = "new_field")]
-                old_field: String
-            }
-        };
-
-        let expanded = expand_derive_partial(&mut input);
-
-        let expected: TokenStream = parse_quote! {
-            #[derive(Default, Debug)]
-            #[some_attr]
-            #[serde(default)]
-            struct PartialData {
-                /// A documented field.
-                #[some_attr]
-                str_field: Option<String>,
-                #[some_attr]
-                number_field: Option<f32>,
-                transparent_field: Option<String>,
-                new_field: Option<String>
-            }
-
-            impl partially::Partial for Data {
-                type Item = PartialData;
-
-                fn apply_some(&mut self, partial: Self::Item) -> bool {
-                    let will_apply_some = partial.str_field.is_some() ||
-                        partial.number_field.is_some() ||
-                        partial.transparent_field.is_some() ||
-                        partial.new_field.is_some();
-
-                    if let Some(str_field) = partial.str_field {
-                        self.str_field = str_field.into();
-                    }
-
-                    if let Some(number_field) = partial.number_field {
-                        self.number_field = number_field.into();
-                    }
-
-                    if let Some(transparent_field) = partial.transparent_field {
-                        self.transparent_field = transparent_field.into();
-                    }
-
-                    if let Some(new_field) = partial.new_field {
-                        self.old_field = new_field.into();
-                    }
-
-                    will_apply_some
-                }
-            }
-
-            impl partially::Partial for PartialData {
-                type Item = PartialData;
-
-                fn apply_some(&mut self, partial: Self::Item) -> bool {
-                    let will_apply_some = partial.str_field.is_some() ||
-                        partial.number_field.is_some() ||
-                        partial.transparent_field.is_some() ||
-                        partial.new_field.is_some();
-
-                    if let Some(str_field) = partial.str_field {
-                        self.str_field = str_field.into();
-                    }
-
-                    if let Some(number_field) = partial.number_field {
-                        self.number_field = number_field.into();
-                    }
-
-                    if let Some(transparent_field) = partial.transparent_field {
-                        self.transparent_field = transparent_field.into();
-                    }
-
-                    if let Some(new_field) = partial.new_field {
-                        self.old_field = new_field.into();
-                    }
-
-                    will_apply_some
-                }
-            }
-        };
-
-        assert_eq!(expanded.to_string(), expected.to_string());
-    }
-
-    #[test]
-    fn basic_e2e_named() {
-        let mut input: DeriveInput = parse_quote! {
-            #[derive(partially::Partial, Default, Debug)]
-            #[partially(rename = "OptData")]
-            #[partially(derive(Default, Debug))]
-            #[some_attr]
-            struct Data {
-                /// A documented field.
-                #[some_attr]
-                str_field: String,
-                #[partially(omit)]
-                skipped_field: String,
-                #[partially(as_type = "Option<f32>")]
-                #[some_attr]
-                number_field: i32,
-                #[partially(transparent)]
-                transparent_field: Option<String>,
-                #[partially(rename = "new_field")]
-                old_field: String
-            }
-        };
-
-        let expanded = expand_derive_partial(&mut input);
-
-        let expected: TokenStream = parse_quote! {
-            #[derive(Default, Debug)]
-            #[some_attr]
-            struct OptData {
-                /// A documented field.
-                #[some_attr]
-                str_field: Option<String>,
-                #[some_attr]
-                number_field: Option<f32>,
-                transparent_field: Option<String>,
-                new_field: Option<String>
-            }
-
-            impl partially::Partial for Data {
-                type Item = OptData;
-
-                fn apply_some(&mut self, partial: Self::Item) -> bool {
-                    let will_apply_some = partial.str_field.is_some() ||
-                        partial.number_field.is_some() ||
-                        partial.transparent_field.is_some() ||
-                        partial.new_field.is_some();
-
-                    if let Some(str_field) = partial.str_field {
-                        self.str_field = str_field.into();
-                    }
-
-                    if let Some(number_field) = partial.number_field {
-                        self.number_field = number_field.into();
-                    }
-
-                    if let Some(transparent_field) = partial.transparent_field {
-                        self.transparent_field = transparent_field.into();
-                    }
-
-                    if let Some(new_field) = partial.new_field {
-                        self.old_field = new_field.into();
-                    }
-
-                    will_apply_some
-                }
-            }
-
-            impl partially::Partial for OptData {
-                type Item = OptData;
-
-                fn apply_some(&mut self, partial: Self::Item) -> bool {
-                    let will_apply_some = partial.str_field.is_some() ||
-                        partial.number_field.is_some() ||
-                        partial.transparent_field.is_some() ||
-                        partial.new_field.is_some();
-
-                    if let Some(str_field) = partial.str_field {
-                        self.str_field = str_field.into();
-                    }
-
-                    if let Some(number_field) = partial.number_field {
-                        self.number_field = number_field.into();
-                    }
-
-                    if let Some(transparent_field) = partial.transparent_field {
-                        self.transparent_field = transparent_field.into();
-                    }
-
-                    if let Some(new_field) = partial.new_field {
-                        self.old_field = new_field.into();
-                    }
-
-                    will_apply_some
-                }
-            }
-        };
-
-        assert_eq!(expanded.to_string(), expected.to_string());
-    }
-
-    #[test]
-    fn generic_e2e() {
-        let mut input: DeriveInput = parse_quote! {
-            #[derive(partially::Partial, Default, Debug)]
-            #[partially(rename = "PartialData")]
-            #[partially(derive(Default, Debug))]
-            #[some_attr]
-            struct Data<T> {
-                /// A documented field.
-                #[some_attr]
-                type_field: T,
-                #[partially(omit)]
-                skipped_field: String,
-                #[partially(as_type = "Option<f32>")]
-                #[some_attr]
-                number_field: i32,
-                #[partially(transparent)]
-                transparent_field: Option<String>,
-                #[partially(rename = "new_field")]
-                old_field: String
-            }
-        };
-
-        let expanded = expand_derive_partial(&mut input);
-
-        let expected: TokenStream = parse_quote! {
-            #[derive(Default, Debug)]
-            #[some_attr]
-            struct PartialData<T> {
-                /// A documented field.
-                #[some_attr]
-                type_field: Option<T>,
-                #[some_attr]
-                number_field: Option<f32>,
-                transparent_field: Option<String>,
-                new_field: Option<String>
-            }
-
-            impl<T> partially::Partial for Data<T> {
-                type Item = PartialData<T>;
-
-                fn apply_some(&mut self, partial: Self::Item) -> bool {
-                    let will_apply_some = partial.type_field.is_some() ||
-                        partial.number_field.is_some() ||
-                        partial.transparent_field.is_some() ||
-                        partial.new_field.is_some();
-
-                    if let Some(type_field) = partial.type_field {
-                        self.type_field = type_field.into();
-                    }
-
-                    if let Some(number_field) = partial.number_field {
-                        self.number_field = number_field.into();
-                    }
-
-                    if let Some(transparent_field) = partial.transparent_field {
-                        self.transparent_field = transparent_field.into();
-                    }
-
-                    if let Some(new_field) = partial.new_field {
-                        self.old_field = new_field.into();
-                    }
-
-                    will_apply_some
-                }
-            }
-
-            impl<T> partially::Partial for PartialData<T> {
-                type Item = PartialData<T>;
-
-                fn apply_some(&mut self, partial: Self::Item) -> bool {
-                    let will_apply_some = partial.type_field.is_some() ||
-                        partial.number_field.is_some() ||
-                        partial.transparent_field.is_some() ||
-                        partial.new_field.is_some();
-
-                    if let Some(type_field) = partial.type_field {
-                        self.type_field = type_field.into();
-                    }
-
-                    if let Some(number_field) = partial.number_field {
-                        self.number_field = number_field.into();
-                    }
-
-                    if let Some(transparent_field) = partial.transparent_field {
-                        self.transparent_field = transparent_field.into();
-                    }
-
-                    if let Some(new_field) = partial.new_field {
-                        self.old_field = new_field.into();
-                    }
-
-                    will_apply_some
-                }
-            }
-        };
-
-        assert_eq!(expanded.to_string(), expected.to_string());
-    }
-
-    #[test]
-    fn generic_constrained_e2e() {
-        let mut input: DeriveInput = parse_quote! {
-            #[derive(partially::Partial, Default, Debug)]
-            #[partially(rename = "PartialData")]
-            #[partially(derive(Default, Debug))]
-            #[partially(crate = "custom_partially")]
-            #[some_attr]
-            struct Data<T> where T: Sized {
-                /// A documented field.
-                #[some_attr]
-                type_field: T,
-                #[partially(omit)]
-                skipped_field: String,
-                #[partially(as_type = "Option<f32>")]
-                #[some_attr]
-                number_field: i32,
-                #[partially(transparent)]
-                transparent_field: Option<String>,
-                #[partially(rename = "new_field")]
-                old_field: String
-            }
-        };
-
-        let expanded = expand_derive_partial(&mut input);
-
-        let expected: TokenStream = parse_quote! {
-            #[derive(Default, Debug)]
-            #[some_attr]
-            struct PartialData<T> where T: Sized {
-                /// A documented field.
-                #[some_attr]
-                type_field: Option<T>,
-                #[some_attr]
-                number_field: Option<f32>,
-                transparent_field: Option<String>,
-                new_field: Option<String>
-            }
-
-            impl<T> custom_partially::Partial for Data<T> where T : Sized {
-                type Item = PartialData<T>;
-
-                fn apply_some(&mut self, partial: Self::Item) -> bool {
-                    let will_apply_some = partial.type_field.is_some() ||
-                        partial.number_field.is_some() ||
-                        partial.transparent_field.is_some() ||
-                        partial.new_field.is_some();
-
-                    if let Some(type_field) = partial.type_field {
-                        self.type_field = type_field.into();
-                    }
-
-                    if let Some(number_field) = partial.number_field {
-                        self.number_field = number_field.into();
-                    }
-
-                    if let Some(transparent_field) = partial.transparent_field {
-                        self.transparent_field = transparent_field.into();
-                    }
-
-                    if let Some(new_field) = partial.new_field {
-                        self.old_field = new_field.into();
-                    }
-
-                    will_apply_some
-                }
-            }
-
-            impl<T> custom_partially::Partial for PartialData<T> where T : Sized {
-                type Item = PartialData<T>;
-
-                fn apply_some(&mut self, partial: Self::Item) -> bool {
-                    let will_apply_some = partial.type_field.is_some() ||
-                        partial.number_field.is_some() ||
-                        partial.transparent_field.is_some() ||
-                        partial.new_field.is_some();
-
-                    if let Some(type_field) = partial.type_field {
-                        self.type_field = type_field.into();
-                    }
-
-                    if let Some(number_field) = partial.number_field {
-                        self.number_field = number_field.into();
-                    }
-
-                    if let Some(transparent_field) = partial.transparent_field {
-                        self.transparent_field = transparent_field.into();
-                    }
-
-                    if let Some(new_field) = partial.new_field {
-                        self.old_field = new_field.into();
-                    }
-
-                    will_apply_some
-                }
-            }
-        };
-
-        assert_eq!(expanded.to_string(), expected.to_string());
-    }
-
-    #[test]
-    fn extensive_attr_e2e() {
-        let mut input: DeriveInput = parse_quote! {
-            #[derive(partially::Partial, Default, Debug)]
-            #[partially(derive(PartialEq))]
-            #[partially(attribute(serde(default)))]
-            #[partially(attribute(serde(rename = "PascalCase")))]
-            #[partially(skip_attributes)]
-            #[some_attr]
-            struct Data {
-                /// A documented field.
-                #[some_attr]
-                str_field: String,
-                #[partially(omit)]
-                skipped_field: String,
-                #[partially(as_type = "Option<f32>")]
-                #[some_attr]
-                number_field: i32,
-                #[partially(transparent)]
-                transparent_field: Option<String>,
-                #[partially(rename = "new_field")]
-                old_field: String
-            }
-        };
-
-        let expanded = expand_derive_partial(&mut input);
-
-        let expected: TokenStream = parse_quote! {
-            #[derive(PartialEq)]
-            #[serde(default)]
-            #[serde(rename = "PascalCase")]
-            struct PartialData {
-                /// A documented field.
-                #[some_attr]
-                str_field: Option<String>,
-                #[some_attr]
-                number_field: Option<f32>,
-                transparent_field: Option<String>,
-                new_field: Option<String>
-            }
-
-            impl partially::Partial for Data {
-                type Item = PartialData;
-
-                fn apply_some(&mut self, partial: Self::Item) -> bool {
-                    let will_apply_some = partial.str_field.is_some() ||
-                        partial.number_field.is_some() ||
-                        partial.transparent_field.is_some() ||
-                        partial.new_field.is_some();
-
-                    if let Some(str_field) = partial.str_field {
-                        self.str_field = str_field.into();
-                    }
-
-                    if let Some(number_field) = partial.number_field {
-                        self.number_field = number_field.into();
-                    }
-
-                    if let Some(transparent_field) = partial.transparent_field {
-                        self.transparent_field = transparent_field.into();
-                    }
-
-                    if let Some(new_field) = partial.new_field {
-                        self.old_field = new_field.into();
-                    }
-
-                    will_apply_some
-                }
-            }
-
-            impl partially::Partial for PartialData {
-                type Item = PartialData;
-
-                fn apply_some(&mut self, partial: Self::Item) -> bool {
-                    let will_apply_some = partial.str_field.is_some() ||
-                        partial.number_field.is_some() ||
-                        partial.transparent_field.is_some() ||
-                        partial.new_field.is_some();
-
-                    if let Some(str_field) = partial.str_field {
-                        self.str_field = str_field.into();
-                    }
-
-                    if let Some(number_field) = partial.number_field {
-                        self.number_field = number_field.into();
-                    }
-
-                    if let Some(transparent_field) = partial.transparent_field {
-                        self.transparent_field = transparent_field.into();
-                    }
-
-                    if let Some(new_field) = partial.new_field {
-                        self.old_field = new_field.into();
-                    }
-
-                    will_apply_some
-                }
-            }
-        };
-
-        assert_eq!(expanded.to_string(), expected.to_string());
-    }
-}
+use darling::FromDeriveInput;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::DeriveInput;
+
+use self::derive_receiver::DeriveReceiver;
+
+mod derive_receiver;
+mod field_receiver;
+mod impl_diff;
+mod impl_field_names;
+mod impl_from_env;
+mod impl_merge;
+mod impl_partial;
+mod impl_partial_enum;
+mod impl_report;
+mod impl_set_field;
+mod impl_try_from;
+mod impl_try_partial;
+mod impl_undo;
+mod meta_attribute;
+mod phantom_marker;
+mod token_vec;
+mod variant_receiver;
+
+pub fn expand_derive_partial(item: &mut DeriveInput) -> TokenStream {
+    let maybe_receiver = DeriveReceiver::from_derive_input(item);
+
+    match maybe_receiver {
+        Ok(receiver) => quote!(#receiver),
+        Err(e) => e.write_errors(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use proc_macro2::{Span, TokenStream};
+    use quote::quote;
+    use syn::{parse_quote, DeriveInput, Ident};
+
+    use super::expand_derive_partial;
+
+    #[test]
+    fn basic_e2e() {
+        let mut input: DeriveInput = parse_quote! {
+            #[derive(partially::Partial, Default, Debug)]
+            #[partially(derive(Default, Debug))]
+            #[partially(attribute(serde(default)))]
+            #[some_attr]
+            struct Data {
+                /// A documented field.
+                #[some_attr]
+                str_field: String,
+                #[partially(omit)]
+                skipped_field: String,
+                #[partially(as_type = "Option<f32>")]
+                #[some_attr]
+                number_field: i32,
+                #[partially(transparent)]
+                transparent_field: Option<String>,
+                #[partially(rename = "new_field")]
+                old_field: String
+            }
+        };
+
+        let expanded = expand_derive_partial(&mut input);
+
+        let expected: TokenStream = parse_quote! {
+            #[derive(Default, Debug)]
+            #[some_attr]
+            #[serde(default)]
+            struct PartialData {
+                /// A documented field.
+                #[some_attr]
+                str_field: Option<String>,
+                #[some_attr]
+                number_field: Option<f32>,
+                transparent_field: Option<String>,
+                new_field: Option<String>
+            }
+
+            impl partially::Partial for Data {
+                type Item = PartialData;
+
+                fn apply_some(&mut self, partial: Self::Item) -> bool {
+                    let will_apply_some = partial.str_field.is_some() ||
+                        partial.number_field.is_some() ||
+                        partial.transparent_field.is_some() ||
+                        partial.new_field.is_some();
+
+                    if let Some(str_field) = partial.str_field {
+                        self.str_field = str_field.into();
+                    }
+
+                    if let Some(number_field) = partial.number_field {
+                        self.number_field = number_field.into();
+                    }
+
+                    if let Some(transparent_field) = partial.transparent_field {
+                        self.transparent_field = transparent_field.into();
+                    }
+
+                    if let Some(new_field) = partial.new_field {
+                        self.old_field = new_field.into();
+                    }
+
+                    will_apply_some
+                }
+            }
+
+            impl partially::Partial for PartialData {
+                type Item = PartialData;
+
+                fn apply_some(&mut self, partial: Self::Item) -> bool {
+                    let will_apply_some = partial.str_field.is_some() ||
+                        partial.number_field.is_some() ||
+                        partial.transparent_field.is_some() ||
+                        partial.new_field.is_some();
+
+                    if let Some(str_field) = partial.str_field {
+                        self.str_field = str_field.into();
+                    }
+
+                    if let Some(number_field) = partial.number_field {
+                        self.number_field = number_field.into();
+                    }
+
+                    if let Some(transparent_field) = partial.transparent_field {
+                        self.transparent_field = transparent_field.into();
+                    }
+
+                    if let Some(new_field) = partial.new_field {
+                        self.new_field = new_field.into();
+                    }
+
+                    will_apply_some
+                }
+            }
+        };
+
+        assert_eq!(expanded.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn basic_e2e_named() {
+        let mut input: DeriveInput = parse_quote! {
+            #[derive(partially::Partial, Default, Debug)]
+            #[partially(rename = "OptData")]
+            #[partially(derive(Default, Debug))]
+            #[some_attr]
+            struct Data {
+                /// A documented field.
+                #[some_attr]
+                str_field: String,
+                #[partially(omit)]
+                skipped_field: String,
+                #[partially(as_type = "Option<f32>")]
+                #[some_attr]
+                number_field: i32,
+                #[partially(transparent)]
+                transparent_field: Option<String>,
+                #[partially(rename = "new_field")]
+                old_field: String
+            }
+        };
+
+        let expanded = expand_derive_partial(&mut input);
+
+        let expected: TokenStream = parse_quote! {
+            #[derive(Default, Debug)]
+            #[some_attr]
+            struct OptData {
+                /// A documented field.
+                #[some_attr]
+                str_field: Option<String>,
+                #[some_attr]
+                number_field: Option<f32>,
+                transparent_field: Option<String>,
+                new_field: Option<String>
+            }
+
+            impl partially::Partial for Data {
+                type Item = OptData;
+
+                fn apply_some(&mut self, partial: Self::Item) -> bool {
+                    let will_apply_some = partial.str_field.is_some() ||
+                        partial.number_field.is_some() ||
+                        partial.transparent_field.is_some() ||
+                        partial.new_field.is_some();
+
+                    if let Some(str_field) = partial.str_field {
+                        self.str_field = str_field.into();
+                    }
+
+                    if let Some(number_field) = partial.number_field {
+                        self.number_field = number_field.into();
+                    }
+
+                    if let Some(transparent_field) = partial.transparent_field {
+                        self.transparent_field = transparent_field.into();
+                    }
+
+                    if let Some(new_field) = partial.new_field {
+                        self.old_field = new_field.into();
+                    }
+
+                    will_apply_some
+                }
+            }
+
+            impl partially::Partial for OptData {
+                type Item = OptData;
+
+                fn apply_some(&mut self, partial: Self::Item) -> bool {
+                    let will_apply_some = partial.str_field.is_some() ||
+                        partial.number_field.is_some() ||
+                        partial.transparent_field.is_some() ||
+                        partial.new_field.is_some();
+
+                    if let Some(str_field) = partial.str_field {
+                        self.str_field = str_field.into();
+                    }
+
+                    if let Some(number_field) = partial.number_field {
+                        self.number_field = number_field.into();
+                    }
+
+                    if let Some(transparent_field) = partial.transparent_field {
+                        self.transparent_field = transparent_field.into();
+                    }
+
+                    if let Some(new_field) = partial.new_field {
+                        self.new_field = new_field.into();
+                    }
+
+                    will_apply_some
+                }
+            }
+        };
+
+        assert_eq!(expanded.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn generic_e2e() {
+        let mut input: DeriveInput = parse_quote! {
+            #[derive(partially::Partial, Default, Debug)]
+            #[partially(rename = "PartialData")]
+            #[partially(derive(Default, Debug))]
+            #[some_attr]
+            struct Data<T> {
+                /// A documented field.
+                #[some_attr]
+                type_field: T,
+                #[partially(omit)]
+                skipped_field: String,
+                #[partially(as_type = "Option<f32>")]
+                #[some_attr]
+                number_field: i32,
+                #[partially(transparent)]
+                transparent_field: Option<String>,
+                #[partially(rename = "new_field")]
+                old_field: String
+            }
+        };
+
+        let expanded = expand_derive_partial(&mut input);
+
+        let expected: TokenStream = parse_quote! {
+            #[derive(Default, Debug)]
+            #[some_attr]
+            struct PartialData<T> {
+                /// A documented field.
+                #[some_attr]
+                type_field: Option<T>,
+                #[some_attr]
+                number_field: Option<f32>,
+                transparent_field: Option<String>,
+                new_field: Option<String>
+            }
+
+            impl<T> partially::Partial for Data<T> {
+                type Item = PartialData<T>;
+
+                fn apply_some(&mut self, partial: Self::Item) -> bool {
+                    let will_apply_some = partial.type_field.is_some() ||
+                        partial.number_field.is_some() ||
+                        partial.transparent_field.is_some() ||
+                        partial.new_field.is_some();
+
+                    if let Some(type_field) = partial.type_field {
+                        self.type_field = type_field.into();
+                    }
+
+                    if let Some(number_field) = partial.number_field {
+                        self.number_field = number_field.into();
+                    }
+
+                    if let Some(transparent_field) = partial.transparent_field {
+                        self.transparent_field = transparent_field.into();
+                    }
+
+                    if let Some(new_field) = partial.new_field {
+                        self.old_field = new_field.into();
+                    }
+
+                    will_apply_some
+                }
+            }
+
+            impl<T> partially::Partial for PartialData<T> {
+                type Item = PartialData<T>;
+
+                fn apply_some(&mut self, partial: Self::Item) -> bool {
+                    let will_apply_some = partial.type_field.is_some() ||
+                        partial.number_field.is_some() ||
+                        partial.transparent_field.is_some() ||
+                        partial.new_field.is_some();
+
+                    if let Some(type_field) = partial.type_field {
+                        self.type_field = type_field.into();
+                    }
+
+                    if let Some(number_field) = partial.number_field {
+                        self.number_field = number_field.into();
+                    }
+
+                    if let Some(transparent_field) = partial.transparent_field {
+                        self.transparent_field = transparent_field.into();
+                    }
+
+                    if let Some(new_field) = partial.new_field {
+                        self.new_field = new_field.into();
+                    }
+
+                    will_apply_some
+                }
+            }
+        };
+
+        assert_eq!(expanded.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn generic_constrained_e2e() {
+        let mut input: DeriveInput = parse_quote! {
+            #[derive(partially::Partial, Default, Debug)]
+            #[partially(rename = "PartialData")]
+            #[partially(derive(Default, Debug))]
+            #[partially(crate = "custom_partially")]
+            #[some_attr]
+            struct Data<T> where T: Sized {
+                /// A documented field.
+                #[some_attr]
+                type_field: T,
+                #[partially(omit)]
+                skipped_field: String,
+                #[partially(as_type = "Option<f32>")]
+                #[some_attr]
+                number_field: i32,
+                #[partially(transparent)]
+                transparent_field: Option<String>,
+                #[partially(rename = "new_field")]
+                old_field: String
+            }
+        };
+
+        let expanded = expand_derive_partial(&mut input);
+
+        let expected: TokenStream = parse_quote! {
+            #[derive(Default, Debug)]
+            #[some_attr]
+            struct PartialData<T> where T: Sized {
+                /// A documented field.
+                #[some_attr]
+                type_field: Option<T>,
+                #[some_attr]
+                number_field: Option<f32>,
+                transparent_field: Option<String>,
+                new_field: Option<String>
+            }
+
+            impl<T> custom_partially::Partial for Data<T> where T : Sized {
+                type Item = PartialData<T>;
+
+                fn apply_some(&mut self, partial: Self::Item) -> bool {
+                    let will_apply_some = partial.type_field.is_some() ||
+                        partial.number_field.is_some() ||
+                        partial.transparent_field.is_some() ||
+                        partial.new_field.is_some();
+
+                    if let Some(type_field) = partial.type_field {
+                        self.type_field = type_field.into();
+                    }
+
+                    if let Some(number_field) = partial.number_field {
+                        self.number_field = number_field.into();
+                    }
+
+                    if let Some(transparent_field) = partial.transparent_field {
+                        self.transparent_field = transparent_field.into();
+                    }
+
+                    if let Some(new_field) = partial.new_field {
+                        self.old_field = new_field.into();
+                    }
+
+                    will_apply_some
+                }
+            }
+
+            impl<T> custom_partially::Partial for PartialData<T> where T : Sized {
+                type Item = PartialData<T>;
+
+                fn apply_some(&mut self, partial: Self::Item) -> bool {
+                    let will_apply_some = partial.type_field.is_some() ||
+                        partial.number_field.is_some() ||
+                        partial.transparent_field.is_some() ||
+                        partial.new_field.is_some();
+
+                    if let Some(type_field) = partial.type_field {
+                        self.type_field = type_field.into();
+                    }
+
+                    if let Some(number_field) = partial.number_field {
+                        self.number_field = number_field.into();
+                    }
+
+                    if let Some(transparent_field) = partial.transparent_field {
+                        self.transparent_field = transparent_field.into();
+                    }
+
+                    if let Some(new_field) = partial.new_field {
+                        self.new_field = new_field.into();
+                    }
+
+                    will_apply_some
+                }
+            }
+        };
+
+        assert_eq!(expanded.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn extensive_attr_e2e() {
+        let mut input: DeriveInput = parse_quote! {
+            #[derive(partially::Partial, Default, Debug)]
+            #[partially(derive(PartialEq))]
+            #[partially(attribute(serde(default)))]
+            #[partially(attribute(serde(rename = "PascalCase")))]
+            #[partially(skip_attributes)]
+            #[some_attr]
+            struct Data {
+                /// A documented field.
+                #[some_attr]
+                str_field: String,
+                #[partially(omit)]
+                skipped_field: String,
+                #[partially(as_type = "Option<f32>")]
+                #[some_attr]
+                number_field: i32,
+                #[partially(transparent)]
+                transparent_field: Option<String>,
+                #[partially(rename = "new_field")]
+                old_field: String
+            }
+        };
+
+        let expanded = expand_derive_partial(&mut input);
+
+        let expected: TokenStream = parse_quote! {
+            #[derive(PartialEq)]
+            #[serde(default)]
+            #[serde(rename = "PascalCase")]
+            struct PartialData {
+                /// A documented field.
+                #[some_attr]
+                str_field: Option<String>,
+                #[some_attr]
+                number_field: Option<f32>,
+                transparent_field: Option<String>,
+                new_field: Option<String>
+            }
+
+            impl partially::Partial for Data {
+                type Item = PartialData;
+
+                fn apply_some(&mut self, partial: Self::Item) -> bool {
+                    let will_apply_some = partial.str_field.is_some() ||
+                        partial.number_field.is_some() ||
+                        partial.transparent_field.is_some() ||
+                        partial.new_field.is_some();
+
+                    if let Some(str_field) = partial.str_field {
+                        self.str_field = str_field.into();
+                    }
+
+                    if let Some(number_field) = partial.number_field {
+                        self.number_field = number_field.into();
+                    }
+
+                    if let Some(transparent_field) = partial.transparent_field {
+                        self.transparent_field = transparent_field.into();
+                    }
+
+                    if let Some(new_field) = partial.new_field {
+                        self.old_field = new_field.into();
+                    }
+
+                    will_apply_some
+                }
+            }
+
+            impl partially::Partial for PartialData {
+                type Item = PartialData;
+
+                fn apply_some(&mut self, partial: Self::Item) -> bool {
+                    let will_apply_some = partial.str_field.is_some() ||
+                        partial.number_field.is_some() ||
+                        partial.transparent_field.is_some() ||
+                        partial.new_field.is_some();
+
+                    if let Some(str_field) = partial.str_field {
+                        self.str_field = str_field.into();
+                    }
+
+                    if let Some(number_field) = partial.number_field {
+                        self.number_field = number_field.into();
+                    }
+
+                    if let Some(transparent_field) = partial.transparent_field {
+                        self.transparent_field = transparent_field.into();
+                    }
+
+                    if let Some(new_field) = partial.new_field {
+                        self.new_field = new_field.into();
+                    }
+
+                    will_apply_some
+                }
+            }
+        };
+
+        assert_eq!(expanded.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn nested_e2e() {
+        let mut input: DeriveInput = parse_quote! {
+            #[derive(partially::Partial)]
+            #[partially(derive(Default))]
+            struct Data {
+                str_field: String,
+                #[partially(nested)]
+                nested_field: NestedData,
+                #[partially(nested, rename = "new_nested_field")]
+                old_nested_field: NestedData
+            }
+        };
+
+        let expanded = expand_derive_partial(&mut input);
+
+        let expected: TokenStream = parse_quote! {
+            #[derive(Default)]
+            struct PartialData {
+                str_field: Option<String>,
+                nested_field: Option< <NestedData as partially::Partial>::Item>,
+                new_nested_field: Option< <NestedData as partially::Partial>::Item>
+            }
+
+            impl partially::Partial for Data {
+                type Item = PartialData;
+
+                fn apply_some(&mut self, partial: Self::Item) -> bool {
+                    let mut will_apply_some = partial.str_field.is_some();
+
+                    if let Some(str_field) = partial.str_field {
+                        self.str_field = str_field.into();
+                    }
+
+                    if let Some(nested_field) = partial.nested_field {
+                        will_apply_some |= partially::Partial::apply_some(&mut self.nested_field, nested_field);
+                    }
+
+                    if let Some(new_nested_field) = partial.new_nested_field {
+                        will_apply_some |= partially::Partial::apply_some(&mut self.old_nested_field, new_nested_field);
+                    }
+
+                    will_apply_some
+                }
+            }
+
+            impl partially::Partial for PartialData {
+                type Item = PartialData;
+
+                fn apply_some(&mut self, partial: Self::Item) -> bool {
+                    let will_apply_some = partial.str_field.is_some() ||
+                        partial.nested_field.is_some() ||
+                        partial.new_nested_field.is_some();
+
+                    if let Some(str_field) = partial.str_field {
+                        self.str_field = str_field.into();
+                    }
+
+                    if let Some(nested_field) = partial.nested_field {
+                        self.nested_field = nested_field.into();
+                    }
+
+                    if let Some(new_nested_field) = partial.new_nested_field {
+                        self.new_nested_field = new_nested_field.into();
+                    }
+
+                    will_apply_some
+                }
+            }
+        };
+
+        assert_eq!(expanded.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn try_partial_e2e() {
+        let mut input: DeriveInput = parse_quote! {
+            #[derive(partially::Partial)]
+            #[partially(error = "DataError")]
+            struct Data {
+                str_field: String,
+                #[partially(as_type = "Option<i64>", try_into)]
+                number_field: i32,
+                #[partially(validate = "validate_port", rename = "new_port")]
+                port: u16,
+                #[partially(nested)]
+                nested_field: NestedData
+            }
+        };
+
+        let expanded = expand_derive_partial(&mut input);
+
+        let expected: TokenStream = parse_quote! {
+            struct PartialData {
+                str_field: Option<String>,
+                number_field: Option<i64>,
+                new_port: Option<u16>,
+                nested_field: Option< <NestedData as partially::Partial>::Item>
+            }
+
+            impl partially::Partial for Data {
+                type Item = PartialData;
+
+                fn apply_some(&mut self, partial: Self::Item) -> bool {
+                    let mut will_apply_some = partial.str_field.is_some();
+
+                    if let Some(str_field) = partial.str_field {
+                        self.str_field = str_field.into();
+                    }
+
+                    if let Some(number_field) = partial.number_field {
+                        if let Ok(number_field) = core::convert::TryInto::<i32>::try_into(number_field) {
+                            self.number_field = number_field;
+                            will_apply_some = true;
+                        }
+                    }
+
+                    if let Some(new_port) = partial.new_port {
+                        let new_port: u16 = new_port.into();
+                        if validate_port(&new_port).is_ok() {
+                            self.port = new_port;
+                            will_apply_some = true;
+                        }
+                    }
+
+                    if let Some(nested_field) = partial.nested_field {
+                        will_apply_some |= partially::Partial::apply_some(&mut self.nested_field, nested_field);
+                    }
+
+                    will_apply_some
+                }
+            }
+
+            impl partially::Partial for PartialData {
+                type Item = PartialData;
+
+                fn apply_some(&mut self, partial: Self::Item) -> bool {
+                    let will_apply_some = partial.str_field.is_some() ||
+                        partial.number_field.is_some() ||
+                        partial.new_port.is_some() ||
+                        partial.nested_field.is_some();
+
+                    if let Some(str_field) = partial.str_field {
+                        self.str_field = str_field.into();
+                    }
+
+                    if let Some(number_field) = partial.number_field {
+                        self.number_field = number_field.into();
+                    }
+
+                    if let Some(new_port) = partial.new_port {
+                        self.new_port = new_port.into();
+                    }
+
+                    if let Some(nested_field) = partial.nested_field {
+                        self.nested_field = nested_field.into();
+                    }
+
+                    will_apply_some
+                }
+            }
+
+            impl partially::TryPartial for Data {
+                type Item = PartialData;
+                type Error = DataError;
+
+                fn try_apply_some(&mut self, partial: Self::Item) -> Result<bool, Self::Error> {
+                    let mut will_apply_some = partial.str_field.is_some() ||
+                        partial.number_field.is_some() ||
+                        partial.new_port.is_some();
+
+                    let number_field: Option<i32> = partial.number_field
+                        .map(core::convert::TryInto::try_into)
+                        .transpose()?;
+
+                    let new_port: Option<u16> = partial.new_port.map(Into::into);
+                    if let Some(new_port) = &new_port {
+                        validate_port(new_port)?;
+                    }
+
+                    let nested_field: Option<(NestedData, bool)> = match partial.nested_field {
+                        Some(nested_field) => {
+                            let mut value = self.nested_field.clone();
+                            let applied = partially::TryPartial::try_apply_some(&mut value, nested_field)?;
+
+                            Some((value, applied))
+                        }
+                        None => None,
+                    };
+
+                    if let Some(str_field) = partial.str_field {
+                        self.str_field = str_field.into();
+                    }
+
+                    if let Some(number_field) = number_field {
+                        self.number_field = number_field;
+                    }
+
+                    if let Some(new_port) = new_port {
+                        self.port = new_port;
+                    }
+
+                    if let Some((value, applied)) = nested_field {
+                        self.nested_field = value;
+                        will_apply_some |= applied;
+                    }
+
+                    Ok(will_apply_some)
+                }
+            }
+        };
+
+        assert_eq!(expanded.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn diff_e2e() {
+        let mut input: DeriveInput = parse_quote! {
+            #[derive(partially::Partial)]
+            #[partially(diff)]
+            struct Data {
+                str_field: String,
+                #[partially(omit)]
+                skipped_field: String,
+                #[partially(rename = "new_field")]
+                old_field: String,
+                #[partially(nested)]
+                nested_field: NestedData
+            }
+        };
+
+        let expanded = expand_derive_partial(&mut input);
+
+        let expected: TokenStream = parse_quote! {
+            struct PartialData {
+                str_field: Option<String>,
+                new_field: Option<String>,
+                nested_field: Option< <NestedData as partially::Partial>::Item>
+            }
+
+            impl partially::Partial for Data {
+                type Item = PartialData;
+
+                fn apply_some(&mut self, partial: Self::Item) -> bool {
+                    let mut will_apply_some = partial.str_field.is_some() ||
+                        partial.new_field.is_some();
+
+                    if let Some(str_field) = partial.str_field {
+                        self.str_field = str_field.into();
+                    }
+
+                    if let Some(new_field) = partial.new_field {
+                        self.old_field = new_field.into();
+                    }
+
+                    if let Some(nested_field) = partial.nested_field {
+                        will_apply_some |= partially::Partial::apply_some(&mut self.nested_field, nested_field);
+                    }
+
+                    will_apply_some
+                }
+            }
+
+            impl partially::Partial for PartialData {
+                type Item = PartialData;
+
+                fn apply_some(&mut self, partial: Self::Item) -> bool {
+                    let will_apply_some = partial.str_field.is_some() ||
+                        partial.new_field.is_some() ||
+                        partial.nested_field.is_some();
+
+                    if let Some(str_field) = partial.str_field {
+                        self.str_field = str_field.into();
+                    }
+
+                    if let Some(new_field) = partial.new_field {
+                        self.new_field = new_field.into();
+                    }
+
+                    if let Some(nested_field) = partial.nested_field {
+                        self.nested_field = nested_field.into();
+                    }
+
+                    will_apply_some
+                }
+            }
+
+            impl partially::Diff for Data {
+                fn diff(&self, other: &Self) -> Self::Item {
+                    PartialData {
+                        str_field: if self.str_field != other.str_field {
+                            Some(other.str_field.clone())
+                        } else {
+                            None
+                        },
+                        new_field: if self.old_field != other.old_field {
+                            Some(other.old_field.clone())
+                        } else {
+                            None
+                        },
+                        nested_field: if self.nested_field != other.nested_field {
+                            Some(partially::Diff::diff(&self.nested_field, &other.nested_field))
+                        } else {
+                            None
+                        }
+                    }
+                }
+            }
+        };
+
+        assert_eq!(expanded.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn diff_transparent_e2e() {
+        let mut input: DeriveInput = parse_quote! {
+            #[derive(partially::Partial)]
+            #[partially(diff)]
+            struct Data {
+                #[partially(transparent)]
+                transparent_field: Option<String>,
+                #[partially(transparent)]
+                vec_field: Vec<u8>
+            }
+        };
+
+        let expanded = expand_derive_partial(&mut input);
+
+        let expected: TokenStream = parse_quote! {
+            struct PartialData {
+                transparent_field: Option<String>,
+                vec_field: Vec<u8>
+            }
+
+            impl partially::Partial for Data {
+                type Item = PartialData;
+
+                fn apply_some(&mut self, partial: Self::Item) -> bool {
+                    let will_apply_some = partial.transparent_field.is_some() || true;
+
+                    if let Some(transparent_field) = partial.transparent_field {
+                        self.transparent_field = transparent_field.into();
+                    }
+
+                    let vec_field = partial.vec_field;
+                    self.vec_field = vec_field.into();
+
+                    will_apply_some
+                }
+            }
+
+            impl partially::Partial for PartialData {
+                type Item = PartialData;
+
+                fn apply_some(&mut self, partial: Self::Item) -> bool {
+                    let will_apply_some = partial.transparent_field.is_some() || true;
+
+                    if let Some(transparent_field) = partial.transparent_field {
+                        self.transparent_field = transparent_field.into();
+                    }
+
+                    let vec_field = partial.vec_field;
+                    self.vec_field = vec_field.into();
+
+                    will_apply_some
+                }
+            }
+
+            impl partially::Diff for Data {
+                fn diff(&self, other: &Self) -> Self::Item {
+                    PartialData {
+                        transparent_field: if self.transparent_field != other.transparent_field {
+                            other.transparent_field.clone()
+                        } else {
+                            None
+                        },
+                        vec_field: other.vec_field.clone()
+                    }
+                }
+            }
+        };
+
+        assert_eq!(expanded.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn diff_as_type_e2e() {
+        let mut input: DeriveInput = parse_quote! {
+            #[derive(partially::Partial)]
+            #[partially(diff)]
+            struct Data {
+                #[partially(as_type = "Option<f32>")]
+                number_field: i32
+            }
+        };
+
+        let expanded = expand_derive_partial(&mut input).to_string();
+
+        assert!(expanded.contains("cannot use as_type with diff"));
+    }
+
+    #[test]
+    fn merge_e2e() {
+        let mut input: DeriveInput = parse_quote! {
+            #[derive(partially::Partial)]
+            #[partially(merge)]
+            struct Data {
+                str_field: String,
+                #[partially(rename = "new_field")]
+                old_field: String,
+                #[partially(nested)]
+                nested_field: NestedData
+            }
+        };
+
+        let expanded = expand_derive_partial(&mut input);
+
+        let expected: TokenStream = parse_quote! {
+            struct PartialData {
+                str_field: Option<String>,
+                new_field: Option<String>,
+                nested_field: Option< <NestedData as partially::Partial>::Item>
+            }
+
+            impl partially::Partial for Data {
+                type Item = PartialData;
+
+                fn apply_some(&mut self, partial: Self::Item) -> bool {
+                    let mut will_apply_some = partial.str_field.is_some() ||
+                        partial.new_field.is_some();
+
+                    if let Some(str_field) = partial.str_field {
+                        self.str_field = str_field.into();
+                    }
+
+                    if let Some(new_field) = partial.new_field {
+                        self.old_field = new_field.into();
+                    }
+
+                    if let Some(nested_field) = partial.nested_field {
+                        will_apply_some |= partially::Partial::apply_some(&mut self.nested_field, nested_field);
+                    }
+
+                    will_apply_some
+                }
+            }
+
+            impl partially::Partial for PartialData {
+                type Item = PartialData;
+
+                fn apply_some(&mut self, partial: Self::Item) -> bool {
+                    let will_apply_some = partial.str_field.is_some() ||
+                        partial.new_field.is_some() ||
+                        partial.nested_field.is_some();
+
+                    if let Some(str_field) = partial.str_field {
+                        self.str_field = str_field.into();
+                    }
+
+                    if let Some(new_field) = partial.new_field {
+                        self.new_field = new_field.into();
+                    }
+
+                    if let Some(nested_field) = partial.nested_field {
+                        self.nested_field = nested_field.into();
+                    }
+
+                    will_apply_some
+                }
+            }
+
+            impl partially::Merge for PartialData {
+                fn merge(self, other: Self, policy: partially::MergePolicy) -> Result<Self, partially::MergeConflict> {
+                    Ok(Self {
+                        str_field: match (self.str_field, other.str_field) {
+                            (Some(left), Some(right)) => Some(partially::MergePolicy::resolve(policy, "str_field", left, right)?),
+                            (left, right) => left.or(right),
+                        },
+                        new_field: match (self.new_field, other.new_field) {
+                            (Some(left), Some(right)) => Some(partially::MergePolicy::resolve(policy, "new_field", left, right)?),
+                            (left, right) => left.or(right),
+                        },
+                        nested_field: match (self.nested_field, other.nested_field) {
+                            (Some(left), Some(right)) => Some(
+                                partially::Merge::merge(left, right, policy)
+                                    .map_err(|conflict| partially::MergeConflict::nested(conflict, "nested_field"))?
+                            ),
+                            (left, right) => left.or(right),
+                        }
+                    })
+                }
+
+                fn overlay(self, other: Self) -> Self {
+                    Self {
+                        str_field: other.str_field.or(self.str_field),
+                        new_field: other.new_field.or(self.new_field),
+                        nested_field: match (self.nested_field, other.nested_field) {
+                            (Some(left), Some(right)) => Some(partially::Merge::overlay(left, right)),
+                            (left, right) => right.or(left),
+                        }
+                    }
+                }
+            }
+        };
+
+        assert_eq!(expanded.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn try_from_e2e() {
+        let mut input: DeriveInput = parse_quote! {
+            #[derive(partially::Partial)]
+            #[partially(try_from)]
+            struct Data {
+                str_field: String,
+                #[partially(omit)]
+                skipped_field: String,
+                #[partially(omit, default = "42")]
+                defaulted_field: i32,
+                #[partially(transparent)]
+                transparent_field: Option<String>,
+                #[partially(rename = "new_field")]
+                old_field: String,
+                #[partially(nested)]
+                nested_field: NestedData
+            }
+        };
+
+        let expanded = expand_derive_partial(&mut input);
+
+        let expected: TokenStream = parse_quote! {
+            struct PartialData {
+                str_field: Option<String>,
+                transparent_field: Option<String>,
+                new_field: Option<String>,
+                nested_field: Option< <NestedData as partially::Partial>::Item>
+            }
+
+            impl partially::Partial for Data {
+                type Item = PartialData;
+
+                fn apply_some(&mut self, partial: Self::Item) -> bool {
+                    let mut will_apply_some = partial.str_field.is_some() ||
+                        partial.transparent_field.is_some() ||
+                        partial.new_field.is_some();
+
+                    if let Some(str_field) = partial.str_field {
+                        self.str_field = str_field.into();
+                    }
+
+                    if let Some(transparent_field) = partial.transparent_field {
+                        self.transparent_field = transparent_field.into();
+                    }
+
+                    if let Some(new_field) = partial.new_field {
+                        self.old_field = new_field.into();
+                    }
+
+                    if let Some(nested_field) = partial.nested_field {
+                        will_apply_some |= partially::Partial::apply_some(&mut self.nested_field, nested_field);
+                    }
+
+                    will_apply_some
+                }
+            }
+
+            impl partially::Partial for PartialData {
+                type Item = PartialData;
+
+                fn apply_some(&mut self, partial: Self::Item) -> bool {
+                    let will_apply_some = partial.str_field.is_some() ||
+                        partial.transparent_field.is_some() ||
+                        partial.new_field.is_some() ||
+                        partial.nested_field.is_some();
+
+                    if let Some(str_field) = partial.str_field {
+                        self.str_field = str_field.into();
+                    }
+
+                    if let Some(transparent_field) = partial.transparent_field {
+                        self.transparent_field = transparent_field.into();
+                    }
+
+                    if let Some(new_field) = partial.new_field {
+                        self.new_field = new_field.into();
+                    }
+
+                    if let Some(nested_field) = partial.nested_field {
+                        self.nested_field = nested_field.into();
+                    }
+
+                    will_apply_some
+                }
+            }
+
+            impl core::convert::TryFrom<PartialData> for Data {
+                type Error = partially::TryFromPartialError;
+
+                fn try_from(partial: PartialData) -> Result<Self, Self::Error> {
+                    let nested_field: Option<NestedData> = partial.nested_field
+                        .map(core::convert::TryFrom::try_from)
+                        .transpose()
+                        .map_err(|error| partially::TryFromPartialError::Invalid(partially::InvalidField::new("nested_field", error)))?;
+
+                    match (partial.str_field, partial.new_field, nested_field,) {
+                        (Some(str_field), Some(new_field), Some(nested_field),) => Ok(Self {
+                            str_field: str_field.into(),
+                            skipped_field: Default::default(),
+                            defaulted_field: 42,
+                            transparent_field: partial.transparent_field,
+                            old_field: new_field.into(),
+                            nested_field: nested_field.into()
+                        }),
+                        (str_field, new_field, nested_field,) => {
+                            let mut missing = partially::MissingFields::new(&["str_field", "new_field", "nested_field"]);
+
+                            if str_field.is_none() {
+                                missing.push("str_field");
+                            }
+
+                            if new_field.is_none() {
+                                missing.push("new_field");
+                            }
+
+                            if nested_field.is_none() {
+                                missing.push("nested_field");
+                            }
+
+                            Err(partially::TryFromPartialError::Missing(missing))
+                        }
+                    }
+                }
+            }
+        };
+
+        assert_eq!(expanded.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn report_e2e() {
+        let mut input: DeriveInput = parse_quote! {
+            #[derive(partially::Partial)]
+            #[partially(report)]
+            pub struct Data {
+                str_field: String,
+                #[partially(omit)]
+                skipped_field: String,
+                #[partially(rename = "new_field")]
+                old_field: String,
+                #[partially(nested)]
+                nested_field: NestedData
+            }
+        };
+
+        let expanded = expand_derive_partial(&mut input);
+
+        let expected: TokenStream = parse_quote! {
+            pub struct PartialData {
+                str_field: Option<String>,
+                new_field: Option<String>,
+                nested_field: Option< <NestedData as partially::Partial>::Item>
+            }
+
+            impl partially::Partial for Data {
+                type Item = PartialData;
+
+                fn apply_some(&mut self, partial: Self::Item) -> bool {
+                    let mut will_apply_some = partial.str_field.is_some() ||
+                        partial.new_field.is_some();
+
+                    if let Some(str_field) = partial.str_field {
+                        self.str_field = str_field.into();
+                    }
+
+                    if let Some(new_field) = partial.new_field {
+                        self.old_field = new_field.into();
+                    }
+
+                    if let Some(nested_field) = partial.nested_field {
+                        will_apply_some |= partially::Partial::apply_some(&mut self.nested_field, nested_field);
+                    }
+
+                    will_apply_some
+                }
+            }
+
+            impl partially::Partial for PartialData {
+                type Item = PartialData;
+
+                fn apply_some(&mut self, partial: Self::Item) -> bool {
+                    let will_apply_some = partial.str_field.is_some() ||
+                        partial.new_field.is_some() ||
+                        partial.nested_field.is_some();
+
+                    if let Some(str_field) = partial.str_field {
+                        self.str_field = str_field.into();
+                    }
+
+                    if let Some(new_field) = partial.new_field {
+                        self.new_field = new_field.into();
+                    }
+
+                    if let Some(nested_field) = partial.nested_field {
+                        self.nested_field = nested_field.into();
+                    }
+
+                    will_apply_some
+                }
+            }
+
+            #[doc = "The set of `Data` fields applied by `apply_some_report`."]
+            #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+            pub struct DataFields(u8);
+
+            impl DataFields {
+                #[doc = "The `str_field` field."]
+                pub const STR_FIELD: Self = Self(1 << 0);
+
+                #[doc = "The `old_field` field."]
+                pub const OLD_FIELD: Self = Self(1 << 1);
+
+                #[doc = "The `nested_field` field."]
+                pub const NESTED_FIELD: Self = Self(1 << 2);
+
+                /// Gets an empty set of fields.
+                pub const fn empty() -> Self {
+                    Self(0)
+                }
+
+                /// Gets the set of all fields.
+                pub const fn all() -> Self {
+                    Self(Self::STR_FIELD.0 | Self::OLD_FIELD.0 | Self::NESTED_FIELD.0)
+                }
+
+                /// Gets the raw bits of the set.
+                pub const fn bits(self) -> u8 {
+                    self.0
+                }
+
+                /// Gets whether the set is empty.
+                pub const fn is_empty(self) -> bool {
+                    self.0 == 0
+                }
+
+                /// Gets whether the set contains every field in `other`.
+                pub const fn contains(self, other: Self) -> bool {
+                    self.0 & other.0 == other.0
+                }
+
+                /// Gets whether the set contains any field in `other`.
+                pub const fn intersects(self, other: Self) -> bool {
+                    self.0 & other.0 != 0
+                }
+
+                /// Adds every field in `other` to the set.
+                pub fn insert(&mut self, other: Self) {
+                    self.0 |= other.0;
+                }
+            }
+
+            impl core::ops::BitOr for DataFields {
+                type Output = Self;
+
+                fn bitor(self, other: Self) -> Self {
+                    Self(self.0 | other.0)
+                }
+            }
+
+            impl core::ops::BitOrAssign for DataFields {
+                fn bitor_assign(&mut self, other: Self) {
+                    self.insert(other)
+                }
+            }
+
+            impl partially::PartialReport for Data {
+                type Report = DataFields;
+
+                fn apply_some_report(&mut self, partial: Self::Item) -> Self::Report {
+                    let mut report = DataFields::empty();
+
+                    if let Some(str_field) = partial.str_field {
+                        self.str_field = str_field.into();
+                        report.insert(DataFields::STR_FIELD);
+                    }
+
+                    if let Some(new_field) = partial.new_field {
+                        self.old_field = new_field.into();
+                        report.insert(DataFields::OLD_FIELD);
+                    }
+
+                    if let Some(nested_field) = partial.nested_field {
+                        if partially::Partial::apply_some(&mut self.nested_field, nested_field) {
+                            report.insert(DataFields::NESTED_FIELD);
+                        }
+                    }
+
+                    report
+                }
+            }
+        };
+
+        assert_eq!(expanded.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn report_too_many_fields_e2e() {
+        let fields = (0..=128).map(|index| {
+            let ident = Ident::new(&format!("field_{}", index), Span::call_site());
+
+            quote!(#ident: u8)
+        });
+
+        let mut input: DeriveInput = parse_quote! {
+            #[derive(partially::Partial)]
+            #[partially(report)]
+            struct Data {
+                #(#fields),*
+            }
+        };
+
+        let expanded = expand_derive_partial(&mut input).to_string();
+
+        assert!(expanded.contains("cannot use report with more than 128 fields"));
+    }
+
+    #[test]
+    fn compare_e2e() {
+        let mut input: DeriveInput = parse_quote! {
+            #[derive(partially::Partial)]
+            #[partially(compare)]
+            struct Data {
+                str_field: String,
+                #[partially(rename = "new_field")]
+                old_field: String,
+                #[partially(nested)]
+                nested_field: NestedData
+            }
+        };
+
+        let expanded = expand_derive_partial(&mut input);
+
+        let expected: TokenStream = parse_quote! {
+            struct PartialData {
+                str_field: Option<String>,
+                new_field: Option<String>,
+                nested_field: Option< <NestedData as partially::Partial>::Item>
+            }
+
+            impl partially::Partial for Data {
+                type Item = PartialData;
+
+                fn apply_some(&mut self, partial: Self::Item) -> bool {
+                    let mut will_apply_some = false;
+
+                    if let Some(str_field) = partial.str_field {
+                        let str_field: String = str_field.into();
+                        if self.str_field != str_field {
+                            self.str_field = str_field;
+                            will_apply_some = true;
+                        }
+                    }
+
+                    if let Some(new_field) = partial.new_field {
+                        let new_field: String = new_field.into();
+                        if self.old_field != new_field {
+                            self.old_field = new_field;
+                            will_apply_some = true;
+                        }
+                    }
+
+                    if let Some(nested_field) = partial.nested_field {
+                        will_apply_some |= partially::Partial::apply_some(&mut self.nested_field, nested_field);
+                    }
+
+                    will_apply_some
+                }
+            }
+
+            impl partially::Partial for PartialData {
+                type Item = PartialData;
+
+                fn apply_some(&mut self, partial: Self::Item) -> bool {
+                    let will_apply_some = partial.str_field.is_some() ||
+                        partial.new_field.is_some() ||
+                        partial.nested_field.is_some();
+
+                    if let Some(str_field) = partial.str_field {
+                        self.str_field = str_field.into();
+                    }
+
+                    if let Some(new_field) = partial.new_field {
+                        self.new_field = new_field.into();
+                    }
+
+                    if let Some(nested_field) = partial.nested_field {
+                        self.nested_field = nested_field.into();
+                    }
+
+                    will_apply_some
+                }
+            }
+        };
+
+        assert_eq!(expanded.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn undo_e2e() {
+        let mut input: DeriveInput = parse_quote! {
+            #[derive(partially::Partial)]
+            #[partially(undo)]
+            struct Data {
+                str_field: String,
+                #[partially(omit)]
+                skipped_field: String,
+                #[partially(rename = "new_field")]
+                old_field: String,
+                #[partially(nested)]
+                nested_field: NestedData
+            }
+        };
+
+        let expanded = expand_derive_partial(&mut input);
+
+        let expected: TokenStream = parse_quote! {
+            struct PartialData {
+                str_field: Option<String>,
+                new_field: Option<String>,
+                nested_field: Option< <NestedData as partially::Partial>::Item>
+            }
+
+            impl partially::Partial for Data {
+                type Item = PartialData;
+
+                fn apply_some(&mut self, partial: Self::Item) -> bool {
+                    let mut will_apply_some = partial.str_field.is_some() ||
+                        partial.new_field.is_some();
+
+                    if let Some(str_field) = partial.str_field {
+                        self.str_field = str_field.into();
+                    }
+
+                    if let Some(new_field) = partial.new_field {
+                        self.old_field = new_field.into();
+                    }
+
+                    if let Some(nested_field) = partial.nested_field {
+                        will_apply_some |= partially::Partial::apply_some(&mut self.nested_field, nested_field);
+                    }
+
+                    will_apply_some
+                }
+            }
+
+            impl partially::Partial for PartialData {
+                type Item = PartialData;
+
+                fn apply_some(&mut self, partial: Self::Item) -> bool {
+                    let will_apply_some = partial.str_field.is_some() ||
+                        partial.new_field.is_some() ||
+                        partial.nested_field.is_some();
+
+                    if let Some(str_field) = partial.str_field {
+                        self.str_field = str_field.into();
+                    }
+
+                    if let Some(new_field) = partial.new_field {
+                        self.new_field = new_field.into();
+                    }
+
+                    if let Some(nested_field) = partial.nested_field {
+                        self.nested_field = nested_field.into();
+                    }
+
+                    will_apply_some
+                }
+            }
+
+            impl partially::PartialUndo for Data {
+                fn apply_some_with_undo(&mut self, partial: Self::Item) -> Self::Item {
+                    let mut undo = PartialData {
+                        str_field: None,
+                        new_field: None,
+                        nested_field: None
+                    };
+
+                    if let Some(str_field) = partial.str_field {
+                        let str_field: String = str_field.into();
+                        undo.str_field = Some(core::mem::replace(&mut self.str_field, str_field));
+                    }
+
+                    if let Some(new_field) = partial.new_field {
+                        let new_field: String = new_field.into();
+                        undo.new_field = Some(core::mem::replace(&mut self.old_field, new_field));
+                    }
+
+                    if let Some(nested_field) = partial.nested_field {
+                        undo.nested_field = Some(partially::PartialUndo::apply_some_with_undo(&mut self.nested_field, nested_field));
+                    }
+
+                    undo
+                }
+            }
+        };
+
+        assert_eq!(expanded.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn undo_as_type_e2e() {
+        let mut input: DeriveInput = parse_quote! {
+            #[derive(partially::Partial)]
+            #[partially(undo)]
+            struct Data {
+                #[partially(as_type = "Option<i64>")]
+                number_field: u8
+            }
+        };
+
+        let expanded = expand_derive_partial(&mut input).to_string();
+
+        assert!(expanded.contains("cannot use transparent or as_type with undo"));
+    }
+
+    #[test]
+    fn patch_e2e() {
+        let mut input: DeriveInput = parse_quote! {
+            #[derive(partially::Partial)]
+            struct Data {
+                str_field: String,
+                #[partially(patch, rename = "new_field")]
+                old_field: Option<String>
+            }
+        };
+
+        let expanded = expand_derive_partial(&mut input);
+
+        let expected: TokenStream = parse_quote! {
+            struct PartialData {
+                str_field: Option<String>,
+                new_field: partially::Patch<String>
+            }
+
+            impl partially::Partial for Data {
+                type Item = PartialData;
+
+                fn apply_some(&mut self, partial: Self::Item) -> bool {
+                    let will_apply_some = partial.str_field.is_some() ||
+                        !partial.new_field.is_missing();
+
+                    if let Some(str_field) = partial.str_field {
+                        self.str_field = str_field.into();
+                    }
+
+                    if let Some(new_field) = partially::Patch::into_option(partial.new_field) {
+                        self.old_field = new_field.into();
+                    }
+
+                    will_apply_some
+                }
+            }
+
+            impl partially::Partial for PartialData {
+                type Item = PartialData;
+
+                fn apply_some(&mut self, partial: Self::Item) -> bool {
+                    let will_apply_some = partial.str_field.is_some() ||
+                        !partial.new_field.is_missing();
+
+                    if let Some(str_field) = partial.str_field {
+                        self.str_field = str_field.into();
+                    }
+
+                    if let Some(new_field) = partially::Patch::into_option(partial.new_field) {
+                        self.new_field = partially::Patch::from(Some(new_field));
+                    }
+
+                    will_apply_some
+                }
+            }
+        };
+
+        assert_eq!(expanded.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn patch_not_option_e2e() {
+        let mut input: DeriveInput = parse_quote! {
+            #[derive(partially::Partial)]
+            struct Data {
+                #[partially(patch)]
+                str_field: String
+            }
+        };
+
+        let expanded = expand_derive_partial(&mut input).to_string();
+
+        assert!(expanded.contains("cannot use patch on a field that is not an Option"));
+    }
+
+    #[test]
+    fn serde_sparse_e2e() {
+        let mut input: DeriveInput = parse_quote! {
+            #[derive(partially::Partial)]
+            #[partially(serde_sparse)]
+            struct Data {
+                str_field: String,
+                #[partially(patch)]
+                opt_field: Option<String>,
+                #[partially(as_type = "Option<i64>")]
+                num_field: u32,
+                #[partially(as_type = "Vec<u8>")]
+                vec_field: Vec<u8>
+            }
+        };
+
+        let expanded = expand_derive_partial(&mut input);
+
+        let expected: TokenStream = parse_quote! {
+            struct PartialData {
+                #[serde(default, skip_serializing_if = "Option::is_none")]
+                str_field: Option<String>,
+                #[serde(default, skip_serializing_if = "partially::Patch::is_missing")]
+                opt_field: partially::Patch<String>,
+                #[serde(default, skip_serializing_if = "Option::is_none")]
+                num_field: Option<i64>,
+                vec_field: Vec<u8>
+            }
+        };
+
+        assert!(expanded.to_string().starts_with(&expected.to_string()));
+    }
+
+    #[test]
+    fn patch_serde_e2e() {
+        let mut input: DeriveInput = parse_quote! {
+            #[derive(partially::Partial)]
+            #[partially(derive(serde::Serialize, serde::Deserialize))]
+            struct Data {
+                str_field: String,
+                #[partially(patch)]
+                opt_field: Option<String>
+            }
+        };
+
+        let expanded = expand_derive_partial(&mut input);
+
+        let expected: TokenStream = parse_quote! {
+            #[derive(serde::Serialize, serde::Deserialize)]
+            struct PartialData {
+                str_field: Option<String>,
+                #[serde(default, skip_serializing_if = "partially::Patch::is_missing")]
+                opt_field: partially::Patch<String>
+            }
+        };
+
+        assert!(expanded.to_string().starts_with(&expected.to_string()));
+    }
+
+    #[test]
+    fn field_names_e2e() {
+        let mut input: DeriveInput = parse_quote! {
+            #[derive(partially::Partial)]
+            #[partially(field_names)]
+            struct Data {
+                str_field: String,
+                #[partially(omit)]
+                omitted_field: String,
+                #[partially(patch, rename = "new_field")]
+                old_field: Option<String>
+            }
+        };
+
+        let expanded = expand_derive_partial(&mut input);
+
+        let expected: TokenStream = parse_quote! {
+            struct PartialData {
+                str_field: Option<String>,
+                new_field: partially::Patch<String>
+            }
+
+            impl partially::Partial for Data {
+                type Item = PartialData;
+
+                fn apply_some(&mut self, partial: Self::Item) -> bool {
+                    let will_apply_some = partial.str_field.is_some() ||
+                        !partial.new_field.is_missing();
+
+                    if let Some(str_field) = partial.str_field {
+                        self.str_field = str_field.into();
+                    }
+
+                    if let Some(new_field) = partially::Patch::into_option(partial.new_field) {
+                        self.old_field = new_field.into();
+                    }
+
+                    will_apply_some
+                }
+            }
+
+            impl partially::Partial for PartialData {
+                type Item = PartialData;
+
+                fn apply_some(&mut self, partial: Self::Item) -> bool {
+                    let will_apply_some = partial.str_field.is_some() ||
+                        !partial.new_field.is_missing();
+
+                    if let Some(str_field) = partial.str_field {
+                        self.str_field = str_field.into();
+                    }
+
+                    if let Some(new_field) = partially::Patch::into_option(partial.new_field) {
+                        self.new_field = partially::Patch::from(Some(new_field));
+                    }
+
+                    will_apply_some
+                }
+            }
+
+            impl partially::FieldNames for PartialData {
+                const FIELD_NAMES: &'static [&'static str] = &["str_field", "new_field"];
+
+                fn field_is_some(&self, index: usize) -> bool {
+                    match index {
+                        0 => self.str_field.is_some(),
+                        1 => !self.new_field.is_missing(),
+                        _ => false
+                    }
+                }
+            }
+        };
+
+        assert_eq!(expanded.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn set_field_e2e() {
+        let mut input: DeriveInput = parse_quote! {
+            #[derive(partially::Partial)]
+            #[partially(set_field)]
+            struct Data<T> {
+                str_field: T,
+                #[partially(patch, rename = "new_field")]
+                old_field: Option<String>,
+                #[partially(as_type = "Vec<u8>")]
+                vec_field: Vec<u8>
+            }
+        };
+
+        let expanded = expand_derive_partial(&mut input).to_string();
+
+        let expected: TokenStream = parse_quote! {
+            impl<T> partially::SetField for PartialData<T> where T: 'static {
+                fn set_field(
+                    &mut self,
+                    name: &str,
+                    value: partially::__private::Box<dyn partially::__private::Any>,
+                ) -> Result<(), partially::FieldError> {
+                    match name {
+                        "str_field" => partially::__private::set_option(&mut self.str_field, "str_field", value),
+                        "new_field" => partially::__private::set_patch(&mut self.new_field, "new_field", value),
+                        "vec_field" => partially::__private::set_value(&mut self.vec_field, "vec_field", value),
+                        _ => Err(partially::__private::unknown(name))
+                    }
+                }
+            }
+        };
+
+        assert!(expanded.ends_with(&expected.to_string()));
+    }
+
+    #[test]
+    fn env_e2e() {
+        let mut input: DeriveInput = parse_quote! {
+            #[derive(partially::Partial)]
+            #[partially(env)]
+            struct Data {
+                str_field: String,
+                #[partially(patch, rename = "new_field")]
+                old_field: Option<String>,
+                #[partially(nested)]
+                nested_field: Inner
+            }
+        };
+
+        let expanded = expand_derive_partial(&mut input).to_string();
+
+        let expected: TokenStream = parse_quote! {
+            impl partially::FromEnv for PartialData {
+                fn from_env_lookup(
+                    prefix: &str,
+                    lookup: &mut dyn FnMut(&str) -> Option<partially::__private::String>,
+                ) -> Result<(Self, bool), partially::EnvError> {
+                    let mut partial = Self {
+                        str_field: None,
+                        new_field: partially::Patch::from(None),
+                        nested_field: None
+                    };
+                    let mut is_some = false;
+
+                    let name = partially::__private::env_name(prefix, "STR_FIELD");
+                    if let Some(value) = lookup(&name) {
+                        partial.str_field = Some(partially::__private::parse_env(&name, &value)?);
+                        is_some = true;
+                    }
+
+                    let name = partially::__private::env_name(prefix, "NEW_FIELD");
+                    if let Some(value) = lookup(&name) {
+                        partial.new_field = partially::Patch::Value(partially::__private::parse_env(&name, &value)?);
+                        is_some = true;
+                    }
+
+                    let (nested_field, is_nested_some) = << Inner as partially::Partial>::Item as partially::FromEnv>::from_env_lookup(
+                        &partially::__private::env_nested_prefix(prefix, "NESTED_FIELD"),
+                        lookup,
+                    )?;
+                    if is_nested_some {
+                        partial.nested_field = Some(nested_field);
+                        is_some = true;
+                    }
+
+                    Ok((partial, is_some))
+                }
+            }
+        };
+
+        assert!(expanded.ends_with(&expected.to_string()));
+    }
+
+    #[test]
+    fn env_as_type_e2e() {
+        let mut input: DeriveInput = parse_quote! {
+            #[derive(partially::Partial)]
+            #[partially(env)]
+            struct Data {
+                #[partially(as_type = "Vec<u8>")]
+                vec_field: Vec<u8>
+            }
+        };
+
+        let expanded = expand_derive_partial(&mut input).to_string();
+
+        assert!(expanded
+            .contains("cannot use transparent or as_type with env, unless the type is an Option"));
+    }
+
+    #[test]
+    fn clap_e2e() {
+        let mut input: DeriveInput = parse_quote! {
+            #[derive(partially::Partial)]
+            #[partially(clap)]
+            struct Data {
+                /// The string field.
+                str_field: String,
+                #[partially(omit)]
+                omitted_field: String,
+                #[partially(rename = "new_field")]
+                old_field: u16,
+                #[partially(nested)]
+                nested_field: Inner
+            }
+        };
+
+        let expanded = expand_derive_partial(&mut input);
+
+        let expected: TokenStream = parse_quote! {
+            #[derive(clap::Args)]
+            struct PartialData {
+                #[doc = r" The string field."]
+                #[arg(long)]
+                str_field: Option<String>,
+                #[arg(long)]
+                new_field: Option<u16>,
+                #[command(flatten)]
+                nested_field: Option< <Inner as partially::Partial>::Item>
+            }
+        };
+
+        assert!(expanded.to_string().starts_with(&expected.to_string()));
+    }
+
+    #[test]
+    fn clap_patch_e2e() {
+        let mut input: DeriveInput = parse_quote! {
+            #[derive(partially::Partial)]
+            #[partially(clap)]
+            struct Data {
+                #[partially(patch)]
+                opt_field: Option<String>
+            }
+        };
+
+        let expanded = expand_derive_partial(&mut input).to_string();
+
+        assert!(expanded.contains("cannot use patch with clap"));
+    }
+
+    #[test]
+    fn clap_transparent_e2e() {
+        let mut input: DeriveInput = parse_quote! {
+            #[derive(partially::Partial)]
+            #[partially(clap)]
+            struct Data {
+                #[partially(transparent)]
+                str_field: String
+            }
+        };
+
+        let expanded = expand_derive_partial(&mut input).to_string();
+
+        assert!(expanded
+            .contains("cannot use transparent or as_type with clap, unless the type is an Option"));
+    }
+
+    #[test]
+    fn transparent_always_e2e() {
+        let mut input: DeriveInput = parse_quote! {
+            #[derive(partially::Partial)]
+            struct Data {
+                str_field: String,
+                #[partially(transparent)]
+                vec_field: Vec<u8>
+            }
+        };
+
+        let expanded = expand_derive_partial(&mut input);
+
+        let expected: TokenStream = parse_quote! {
+            struct PartialData {
+                str_field: Option<String>,
+                vec_field: Vec<u8>
+            }
+
+            impl partially::Partial for Data {
+                type Item = PartialData;
+
+                fn apply_some(&mut self, partial: Self::Item) -> bool {
+                    let will_apply_some = partial.str_field.is_some() || true;
+
+                    if let Some(str_field) = partial.str_field {
+                        self.str_field = str_field.into();
+                    }
+
+                    let vec_field = partial.vec_field;
+                    self.vec_field = vec_field.into();
+
+                    will_apply_some
+                }
+            }
+
+            impl partially::Partial for PartialData {
+                type Item = PartialData;
+
+                fn apply_some(&mut self, partial: Self::Item) -> bool {
+                    let will_apply_some = partial.str_field.is_some() || true;
+
+                    if let Some(str_field) = partial.str_field {
+                        self.str_field = str_field.into();
+                    }
+
+                    let vec_field = partial.vec_field;
+                    self.vec_field = vec_field.into();
+
+                    will_apply_some
+                }
+            }
+        };
+
+        assert_eq!(expanded.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn transparent_never_e2e() {
+        let mut input: DeriveInput = parse_quote! {
+            #[derive(partially::Partial)]
+            #[partially(compare)]
+            struct Data {
+                str_field: String,
+                #[partially(transparent = "never")]
+                vec_field: Vec<u8>
+            }
+        };
+
+        let expanded = expand_derive_partial(&mut input);
+
+        let expected: TokenStream = parse_quote! {
+            struct PartialData {
+                str_field: Option<String>,
+                vec_field: Vec<u8>
+            }
+
+            impl partially::Partial for Data {
+                type Item = PartialData;
+
+                fn apply_some(&mut self, partial: Self::Item) -> bool {
+                    let mut will_apply_some = false;
+
+                    if let Some(str_field) = partial.str_field {
+                        let str_field: String = str_field.into();
+                        if self.str_field != str_field {
+                            self.str_field = str_field;
+                            will_apply_some = true;
+                        }
+                    }
+
+                    will_apply_some
+                }
+            }
+
+            impl partially::Partial for PartialData {
+                type Item = PartialData;
+
+                fn apply_some(&mut self, partial: Self::Item) -> bool {
+                    let will_apply_some = partial.str_field.is_some() || false;
+
+                    if let Some(str_field) = partial.str_field {
+                        self.str_field = str_field.into();
+                    }
+
+                    will_apply_some
+                }
+            }
+        };
+
+        assert_eq!(expanded.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn transparent_unknown_mode_e2e() {
+        let mut input: DeriveInput = parse_quote! {
+            #[derive(partially::Partial)]
+            struct Data {
+                #[partially(transparent = "sometimes")]
+                vec_field: Vec<u8>
+            }
+        };
+
+        let expanded = expand_derive_partial(&mut input).to_string();
+
+        assert!(expanded.contains("Unknown literal value `sometimes`"));
+    }
+
+    #[test]
+    fn tuple_e2e() {
+        let mut input: DeriveInput = parse_quote! {
+            #[derive(partially::Partial)]
+            struct Data(
+                String,
+                #[partially(omit)]
+                u8,
+                #[partially(transparent)]
+                Vec<u8>
+            );
+        };
+
+        let expanded = expand_derive_partial(&mut input);
+
+        let expected: TokenStream = parse_quote! {
+            struct PartialData(Option<String>, Vec<u8>);
+
+            impl partially::Partial for Data {
+                type Item = PartialData;
+
+                fn apply_some(&mut self, partial: Self::Item) -> bool {
+                    let will_apply_some = partial.0.is_some() || true;
+
+                    if let Some(field_0) = partial.0 {
+                        self.0 = field_0.into();
+                    }
+                    let field_1 = partial.1;
+                    self.2 = field_1.into();
+
+                    will_apply_some
+                }
+            }
+
+            impl partially::Partial for PartialData {
+                type Item = PartialData;
+
+                fn apply_some(&mut self, partial: Self::Item) -> bool {
+                    let will_apply_some = partial.0.is_some() || true;
+
+                    if let Some(field_0) = partial.0 {
+                        self.0 = field_0.into();
+                    }
+                    let field_1 = partial.1;
+                    self.1 = field_1.into();
+
+                    will_apply_some
+                }
+            }
+        };
+
+        assert_eq!(expanded.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn tuple_clap_e2e() {
+        let mut input: DeriveInput = parse_quote! {
+            #[derive(partially::Partial)]
+            #[partially(clap)]
+            struct Data(String);
+        };
+
+        let expanded = expand_derive_partial(&mut input).to_string();
+
+        assert!(expanded.contains("cannot use clap with a tuple struct"));
+    }
+
+    #[test]
+    fn enum_e2e() {
+        let mut input: DeriveInput = parse_quote! {
+            #[derive(partially::Partial)]
+            enum Data {
+                Unit,
+                Named {
+                    str_field: String,
+                    #[partially(omit)]
+                    skipped_field: u8
+                },
+                Unnamed(String)
+            }
+        };
+
+        let expanded = expand_derive_partial(&mut input);
+
+        let expected: TokenStream = parse_quote! {
+            enum PartialData {
+                Unit,
+                Named { str_field: Option<String> },
+                Unnamed(Option<String>)
+            }
+
+            impl partially::Partial for Data {
+                type Item = PartialData;
+
+                fn apply_some(&mut self, partial: Self::Item) -> bool {
+                    match partial {
+                        PartialData::Unit {} => {
+                            if let Self::Unit { .. } = self {
+                                false
+                            } else {
+                                *self = Self::Unit {};
+                                true
+                            }
+                        }
+                        PartialData::Named { str_field } => {
+                            if let Self::Named { str_field: self_str_field, .. } = self {
+                                let mut will_apply_some = false;
+
+                                if let Some(str_field) = str_field {
+                                    *self_str_field = str_field.into();
+                                    will_apply_some = true;
+                                }
+
+                                will_apply_some
+                            } else {
+                                if let (Some(str_field),) = (str_field,) {
+                                    let str_field: String = str_field.into();
+                                    *self = Self::Named {
+                                        str_field: str_field,
+                                        skipped_field: Default::default()
+                                    };
+                                    return true;
+                                }
+
+                                false
+                            }
+                        }
+                        PartialData::Unnamed { 0: field_0 } => {
+                            if let Self::Unnamed { 0: self_field_0, .. } = self {
+                                let mut will_apply_some = false;
+
+                                if let Some(field_0) = field_0 {
+                                    *self_field_0 = field_0.into();
+                                    will_apply_some = true;
+                                }
+
+                                will_apply_some
+                            } else {
+                                if let (Some(field_0),) = (field_0,) {
+                                    let field_0: String = field_0.into();
+                                    *self = Self::Unnamed {
+                                        0: field_0
+                                    };
+                                    return true;
+                                }
+
+                                false
+                            }
+                        }
+                    }
+                }
+            }
+
+            impl partially::TryPartial for Data {
+                type Item = PartialData;
+                type Error = partially::VariantError;
+
+                fn try_apply_some(&mut self, partial: Self::Item) -> Result<bool, Self::Error> {
+                    match partial {
+                        PartialData::Unit {} => {
+                            if let Self::Unit { .. } = self {
+                                Ok(false)
+                            } else {
+                                *self = Self::Unit {};
+                                Ok(true)
+                            }
+                        }
+                        PartialData::Named { str_field } => {
+                            if let Self::Named { str_field: self_str_field, .. } = self {
+                                let mut will_apply_some = false;
+
+                                if let Some(str_field) = str_field {
+                                    *self_str_field = str_field.into();
+                                    will_apply_some = true;
+                                }
+
+                                Ok(will_apply_some)
+                            } else {
+                                if let (Some(str_field),) = (str_field,) {
+                                    let str_field: String = str_field.into();
+                                    *self = Self::Named {
+                                        str_field: str_field,
+                                        skipped_field: Default::default()
+                                    };
+                                    return Ok(true);
+                                }
+
+                                Err(partially::VariantError { variant: "Named" })
+                            }
+                        }
+                        PartialData::Unnamed { 0: field_0 } => {
+                            if let Self::Unnamed { 0: self_field_0, .. } = self {
+                                let mut will_apply_some = false;
+
+                                if let Some(field_0) = field_0 {
+                                    *self_field_0 = field_0.into();
+                                    will_apply_some = true;
+                                }
+
+                                Ok(will_apply_some)
+                            } else {
+                                if let (Some(field_0),) = (field_0,) {
+                                    let field_0: String = field_0.into();
+                                    *self = Self::Unnamed {
+                                        0: field_0
+                                    };
+                                    return Ok(true);
+                                }
+
+                                Err(partially::VariantError { variant: "Unnamed" })
+                            }
+                        }
+                    }
+                }
+            }
+
+            impl partially::Partial for PartialData {
+                type Item = PartialData;
+
+                fn apply_some(&mut self, partial: Self::Item) -> bool {
+                    match partial {
+                        PartialData::Unit {} => {
+                            if let Self::Unit { .. } = self {
+                                false
+                            } else {
+                                *self = Self::Unit {};
+                                true
+                            }
+                        }
+                        PartialData::Named { str_field } => {
+                            if let Self::Named { str_field: self_str_field, .. } = self {
+                                let mut will_apply_some = false;
+
+                                if let Some(str_field) = str_field {
+                                    *self_str_field = str_field.into();
+                                    will_apply_some = true;
+                                }
+
+                                will_apply_some
+                            } else {
+                                *self = Self::Named {
+                                    str_field: str_field
+                                };
+                                true
+                            }
+                        }
+                        PartialData::Unnamed { 0: field_0 } => {
+                            if let Self::Unnamed { 0: self_field_0, .. } = self {
+                                let mut will_apply_some = false;
+
+                                if let Some(field_0) = field_0 {
+                                    *self_field_0 = field_0.into();
+                                    will_apply_some = true;
+                                }
+
+                                will_apply_some
+                            } else {
+                                *self = Self::Unnamed {
+                                    0: field_0
+                                };
+                                true
+                            }
+                        }
+                    }
+                }
+            }
+        };
+
+        assert_eq!(expanded.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn enum_unsupported_e2e() {
+        let mut input: DeriveInput = parse_quote! {
+            #[derive(partially::Partial)]
+            #[partially(diff, merge)]
+            enum Data {
+                Unit
+            }
+        };
+
+        let expanded = expand_derive_partial(&mut input).to_string();
+
+        assert!(expanded.contains("cannot use diff with an enum"));
+        assert!(expanded.contains("cannot use merge with an enum"));
+    }
+
+    #[test]
+    fn bound_e2e() {
+        let mut input: DeriveInput = parse_quote! {
+            #[derive(partially::Partial)]
+            #[partially(bound = "T: Clone")]
+            struct Data<T, U> where T: Clone + Default {
+                value: T,
+                #[partially(nested, bound = "U: partially::Partial")]
+                nested: U
+            }
+        };
+
+        let expanded = expand_derive_partial(&mut input);
+
+        let expected: TokenStream = parse_quote! {
+            struct PartialData<T, U> where T: Clone, U: partially::Partial {
+                value: Option<T>,
+                nested: Option< <U as partially::Partial>::Item>
+            }
+
+            impl<T, U> partially::Partial for Data<T, U>
+            where
+                T: Clone + Default,
+                T: Clone,
+                U: partially::Partial
+            {
+                type Item = PartialData<T, U>;
+
+                fn apply_some(&mut self, partial: Self::Item) -> bool {
+                    let mut will_apply_some = partial.value.is_some();
+
+                    if let Some(value) = partial.value {
+                        self.value = value.into();
+                    }
+                    if let Some(nested) = partial.nested {
+                        will_apply_some |= partially::Partial::apply_some(&mut self.nested, nested);
+                    }
+
+                    will_apply_some
+                }
+            }
+
+            impl<T, U> partially::Partial for PartialData<T, U> where T: Clone, U: partially::Partial {
+                type Item = PartialData<T, U>;
+
+                fn apply_some(&mut self, partial: Self::Item) -> bool {
+                    let will_apply_some = partial.value.is_some() || partial.nested.is_some();
+
+                    if let Some(value) = partial.value {
+                        self.value = value.into();
+                    }
+                    if let Some(nested) = partial.nested {
+                        self.nested = nested.into();
+                    }
+
+                    will_apply_some
+                }
+            }
+        };
+
+        assert_eq!(expanded.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn phantom_marker_e2e() {
+        let mut input: DeriveInput = parse_quote! {
+            #[derive(partially::Partial)]
+            #[partially(diff)]
+            struct Data<'a, T> {
+                id: u64,
+                #[partially(omit)]
+                payload: &'a T
+            }
+        };
+
+        let expanded = expand_derive_partial(&mut input);
+
+        let expected: TokenStream = parse_quote! {
+            struct PartialData<'a, T> {
+                id: Option<u64>,
+                #[doc(hidden)]
+                __partially_marker: core::marker::PhantomData<(&'a (), core::marker::PhantomData<T>,)>
+            }
+
+            impl<'a, T> partially::Partial for Data<'a, T> {
+                type Item = PartialData<'a, T>;
+
+                fn apply_some(&mut self, partial: Self::Item) -> bool {
+                    let will_apply_some = partial.id.is_some();
+
+                    if let Some(id) = partial.id {
+                        self.id = id.into();
+                    }
+
+                    will_apply_some
+                }
+            }
+
+            impl<'a, T> partially::Partial for PartialData<'a, T> {
+                type Item = PartialData<'a, T>;
+
+                fn apply_some(&mut self, partial: Self::Item) -> bool {
+                    let will_apply_some = partial.id.is_some();
+
+                    if let Some(id) = partial.id {
+                        self.id = id.into();
+                    }
+
+                    will_apply_some
+                }
+            }
+
+            impl<'a, T> partially::Diff for Data<'a, T> {
+                fn diff(&self, other: &Self) -> Self::Item {
+                    PartialData {
+                        id: if self.id != other.id {
+                            Some(other.id.clone())
+                        } else {
+                            None
+                        },
+                        __partially_marker: core::marker::PhantomData
+                    }
+                }
+            }
+        };
+
+        assert_eq!(expanded.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn phantom_marker_tuple_e2e() {
+        let mut input: DeriveInput = parse_quote! {
+            #[derive(partially::Partial)]
+            #[partially(derive(serde::Serialize))]
+            struct Data<T>(u64, #[partially(omit)] T);
+        };
+
+        let expanded = expand_derive_partial(&mut input).to_string();
+
+        let expected: TokenStream = parse_quote! {
+            #[derive(serde::Serialize)]
+            struct PartialData<T>(
+                Option<u64>,
+                #[doc(hidden)]
+                #[serde(skip)]
+                core::marker::PhantomData<(core::marker::PhantomData<T>,)>
+            );
+        };
+
+        assert!(expanded.starts_with(&expected.to_string()));
+    }
+}
@@ -0,0 +1,139 @@
+use std::collections::HashSet;
+
+use proc_macro2::{TokenStream, TokenTree};
+use quote::{quote, ToTokens};
+use syn::{GenericParam, Generics, Ident, Index, Member, Type};
+
+use super::field_receiver::FieldReceiver;
+
+/// A hidden `PhantomData` marker for the generic parameters that aren't used by the fields of the generated struct
+/// (for example, as the fields using them are `omit`-ted), which would otherwise fail to compile.
+pub struct PhantomMarker {
+    /// The member used to access the marker in the generated struct.
+    pub member: Member,
+
+    /// The `PhantomData` type of the marker.
+    pub ty: Type,
+
+    /// Attributes skipping the marker, for derives of the generated struct that would otherwise include it.
+    pub skip_attrs: TokenStream,
+}
+
+impl PhantomMarker {
+    /// The identifier of the marker field, for generated structs with named fields.
+    const FIELD_NAME: &'static str = "__partially_marker";
+
+    /// The identifier of the marker variant, for generated enums.
+    const VARIANT_NAME: &'static str = "__PartiallyMarker";
+
+    /// Creates a marker for the generic parameters not used by any of `fields`, or [`None`] when each is used.
+    ///
+    /// Note: `fields` are assumed to already be filtered (such that `omit`-ted entries are removed), and `len` is
+    /// the number of fields of a tuple struct - or [`None`] for structs with named fields and enums.
+    pub fn new(
+        generics: &Generics,
+        fields: &[&FieldReceiver],
+        len: Option<usize>,
+        skip_attrs: TokenStream,
+    ) -> Option<Self> {
+        let mut used = HashSet::new();
+        for field in fields {
+            collect_idents(field.dst_type().into_token_stream(), &mut used);
+        }
+
+        // lifetimes are referenced, and types are wrapped, such that the marker doesn't require `Sized`
+        let unused: Vec<_> = generics
+            .params
+            .iter()
+            .filter_map(|param| match param {
+                GenericParam::Type(param) if !used.contains(&param.ident) => {
+                    let ident = &param.ident;
+                    Some(quote!(core::marker::PhantomData<#ident>))
+                }
+                GenericParam::Lifetime(param) if !used.contains(&param.lifetime.ident) => {
+                    let lifetime = &param.lifetime;
+                    Some(quote!(&#lifetime ()))
+                }
+                _ => None,
+            })
+            .collect();
+
+        if unused.is_empty() {
+            return None;
+        }
+
+        let member = match len {
+            Some(len) => Member::Unnamed(Index::from(len)),
+            None => Member::Named(Ident::new(Self::FIELD_NAME, proc_macro2::Span::call_site())),
+        };
+
+        Some(Self {
+            member,
+            ty: syn::parse_quote!(core::marker::PhantomData<(#(#unused,)*)>),
+            skip_attrs,
+        })
+    }
+
+    /// Generates a `member: value` entry that fills the marker, for struct literals of the generated struct.
+    pub fn to_value(&self) -> TokenStream {
+        let member = &self.member;
+
+        quote!(#member: core::marker::PhantomData)
+    }
+
+    /// Generates an uninhabited variant holding the marker, for the generated enum.
+    pub fn to_variant(&self) -> TokenStream {
+        let ident = Ident::new(Self::VARIANT_NAME, proc_macro2::Span::call_site());
+        let Self { ty, skip_attrs, .. } = self;
+
+        quote! {
+            #[doc(hidden)]
+            #skip_attrs
+            #ident(core::convert::Infallible, #ty)
+        }
+    }
+
+    /// Generates a match arm for the marker variant of the generated enum (given by `to_ident`), which is unreachable.
+    pub fn to_variant_arm(&self, to_ident: &Ident) -> TokenStream {
+        let ident = Ident::new(Self::VARIANT_NAME, proc_macro2::Span::call_site());
+
+        quote! {
+            #to_ident::#ident(never, _) => match never {}
+        }
+    }
+}
+
+impl ToTokens for PhantomMarker {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Self {
+            member,
+            ty,
+            skip_attrs,
+        } = self;
+
+        // fields of tuple structs are unnamed
+        let name = match member {
+            Member::Named(ident) => quote!(#ident:),
+            Member::Unnamed(_) => quote!(),
+        };
+
+        tokens.extend(quote! {
+            #[doc(hidden)]
+            #skip_attrs
+            #name #ty
+        })
+    }
+}
+
+/// Collects each identifier in `tokens` (including the identifiers of lifetimes) into `idents`.
+fn collect_idents(tokens: TokenStream, idents: &mut HashSet<Ident>) {
+    for token in tokens {
+        match token {
+            TokenTree::Ident(ident) => {
+                idents.insert(ident);
+            }
+            TokenTree::Group(group) => collect_idents(group.stream(), idents),
+            _ => {}
+        }
+    }
+}
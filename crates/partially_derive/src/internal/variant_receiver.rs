@@ -0,0 +1,98 @@
+use darling::{ast, FromVariant};
+use quote::{format_ident, quote, ToTokens};
+use syn::{Ident, Path};
+
+use super::{
+    field_receiver::FieldReceiver,
+    token_vec::{Separator, TokenVec},
+};
+
+#[derive(Debug, FromVariant)]
+#[darling(attributes(partially), forward_attrs)]
+pub struct VariantReceiver {
+    /// Get the ident of the variant.
+    pub ident: Ident,
+
+    /// Get the attributes of the variant.
+    pub attrs: Vec<syn::Attribute>,
+
+    /// Receives the fields of the variant, which may be named, unnamed or empty.
+    pub fields: ast::Fields<FieldReceiver>,
+}
+
+impl VariantReceiver {
+    /// Gets the fields of the variant that are present in the generated variant.
+    pub fn dst_fields(&self) -> Vec<&FieldReceiver> {
+        self.fields
+            .iter()
+            .filter(|f| !f.omit.is_present())
+            .collect()
+    }
+
+    /// Gets the identifier used to bind the base variant field of `field`, when applying a partial in place.
+    pub fn src_binding(field: &FieldReceiver) -> Ident {
+        format_ident!("self_{}", field.dst_ident())
+    }
+
+    /// Forwards the crate path and the serde options to each field, and populates the positions of the fields.
+    pub fn with_fields(
+        mut self,
+        krate: &Option<Path>,
+        serde_sparse: bool,
+        serde_serialize: bool,
+        serde_deserialize: bool,
+    ) -> Self {
+        let mut dst_index = 0;
+
+        for (index, field) in self.fields.fields.iter_mut().enumerate() {
+            field.index = index;
+            field.dst_index = dst_index;
+            if !field.omit.is_present() {
+                dst_index += 1;
+            }
+
+            field.krate = krate.clone();
+            field.serde_sparse = serde_sparse;
+            field.serde_serialize = serde_serialize;
+            field.serde_deserialize = serde_deserialize;
+        }
+
+        self
+    }
+}
+
+impl ToTokens for VariantReceiver {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let ident = &self.ident;
+        let forwarded_attrs = &self.attrs;
+
+        for attr in forwarded_attrs {
+            tokens.extend(quote! {
+                #attr
+            })
+        }
+
+        let fields = self.dst_fields();
+
+        // keep the style of the base variant
+        match self.fields.style {
+            ast::Style::Struct => {
+                let fields = TokenVec::new_with_vec_and_sep(fields, Separator::Comma);
+
+                tokens.extend(quote! {
+                    #ident { #fields }
+                })
+            }
+            ast::Style::Tuple => {
+                let fields = TokenVec::new_with_vec_and_sep(fields, Separator::Comma);
+
+                tokens.extend(quote! {
+                    #ident(#fields)
+                })
+            }
+            ast::Style::Unit => tokens.extend(quote! {
+                #ident
+            }),
+        }
+    }
+}